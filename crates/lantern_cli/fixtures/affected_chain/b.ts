@@ -0,0 +1,3 @@
+import { C } from "./c";
+
+export const B = C;
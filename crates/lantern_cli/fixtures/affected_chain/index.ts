@@ -0,0 +1,3 @@
+import { A } from "./a";
+
+console.log(A);
@@ -0,0 +1,3 @@
+import { C } from "./c";
+
+console.log(C);
@@ -0,0 +1,3 @@
+import { rarelyUsed } from "./utils";
+
+console.log(rarelyUsed(" a "));
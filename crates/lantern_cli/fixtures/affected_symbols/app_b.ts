@@ -0,0 +1,3 @@
+import { common } from "./utils";
+
+console.log(common("b"));
@@ -0,0 +1,3 @@
+import { format } from "./utils";
+
+console.log(format(1));
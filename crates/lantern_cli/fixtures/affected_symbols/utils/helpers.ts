@@ -0,0 +1,7 @@
+export function rarelyUsed(value: string) {
+  return value.trim();
+}
+
+export function common(value: string) {
+  return value.toUpperCase();
+}
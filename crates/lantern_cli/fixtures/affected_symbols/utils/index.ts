@@ -0,0 +1,2 @@
+export * from "./helpers";
+export { format } from "./format";
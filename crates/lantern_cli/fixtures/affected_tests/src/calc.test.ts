@@ -0,0 +1,3 @@
+import { sum } from "./calc";
+
+console.assert(sum([1, 2]) === 3);
@@ -0,0 +1,5 @@
+import { add } from "./math";
+
+export function sum(values: number[]) {
+  return values.reduce(add, 0);
+}
@@ -0,0 +1,3 @@
+export function format(value: number) {
+  return value.toFixed(2);
+}
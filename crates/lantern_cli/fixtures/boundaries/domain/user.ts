@@ -0,0 +1,3 @@
+import { theme } from "../ui/theme";
+
+export const user = `user in ${theme}`;
@@ -0,0 +1,3 @@
+import { session } from "./internal/session";
+
+export const auth = session;
@@ -0,0 +1,4 @@
+import { store } from "./internal/store";
+import { session } from "../auth/internal/session";
+
+export const cart = store + session;
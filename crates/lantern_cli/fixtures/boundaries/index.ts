@@ -0,0 +1,5 @@
+import { button } from "./ui/button";
+import { cart } from "./features/cart/index";
+import { auth } from "./features/auth/index";
+
+console.log(button, cart, auth);
@@ -0,0 +1,3 @@
+import { user } from "../domain/user";
+
+export const button = `button for ${user}`;
@@ -0,0 +1,5 @@
+import { format } from "../../shared/format";
+
+export function login(user: string) {
+  console.log(format(user));
+}
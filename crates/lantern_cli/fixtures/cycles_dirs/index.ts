@@ -0,0 +1,3 @@
+import { login } from "./features/auth/login";
+
+login("user");
@@ -0,0 +1,5 @@
+import { session } from "../features/auth/session";
+
+export function format(value: string) {
+  return `${session.id}: ${value}`;
+}
@@ -0,0 +1,3 @@
+import { B } from "./b";
+
+export const A = B;
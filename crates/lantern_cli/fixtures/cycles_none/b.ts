@@ -0,0 +1 @@
+export const B = 1;
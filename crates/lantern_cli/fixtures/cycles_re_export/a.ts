@@ -0,0 +1,2 @@
+export * from "./b";
+export const A = 1;
@@ -0,0 +1,4 @@
+import { A } from "./a";
+
+export const B = 1;
+export const C = A;
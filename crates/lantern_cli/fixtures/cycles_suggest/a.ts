@@ -0,0 +1,5 @@
+import { B } from "./b";
+
+export const A1 = B;
+export const A2 = 2;
+export const A3 = 3;
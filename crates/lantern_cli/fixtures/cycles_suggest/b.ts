@@ -0,0 +1,4 @@
+import { A1, A2, A3 } from "./a";
+
+export const B = 1;
+export const C = A1 + A2 + A3;
@@ -0,0 +1,3 @@
+import { A1 } from "./a";
+
+console.log(A1);
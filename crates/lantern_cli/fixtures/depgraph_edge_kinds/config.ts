@@ -0,0 +1,2 @@
+export type Config = { debug: boolean };
+export const DEFAULT_DEBUG = false;
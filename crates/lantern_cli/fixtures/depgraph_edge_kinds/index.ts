@@ -0,0 +1,5 @@
+import { render } from "./ui";
+import type { Config } from "./config";
+
+const config: Config = { debug: true };
+render(config);
@@ -0,0 +1,5 @@
+import { Config } from "./config";
+
+export function render(config: Config) {
+  console.log(config.debug);
+}
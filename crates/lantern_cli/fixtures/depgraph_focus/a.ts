@@ -0,0 +1,6 @@
+import { b } from "./b";
+import { lib } from "./vendor/lib";
+
+export function a() {
+  return b() + lib;
+}
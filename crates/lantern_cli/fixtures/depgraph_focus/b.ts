@@ -0,0 +1,5 @@
+import { c } from "./c";
+
+export function b() {
+  return c;
+}
@@ -0,0 +1,4 @@
+import { b } from "./b";
+
+export const a = b;
+export const unusedA = 2;
@@ -0,0 +1,5 @@
+import { a } from "./a";
+import { r } from "./removed";
+
+export const api = a + r;
+export const old = 2;
@@ -0,0 +1 @@
+export const r = 1;
@@ -0,0 +1,4 @@
+import { a } from "./a";
+
+export const b = () => a;
+export const unusedB = 2;
@@ -0,0 +1,4 @@
+import { a } from "./a";
+
+export const api = a;
+export const added = 3;
@@ -0,0 +1,6 @@
+import { a } from "./a";
+import { x } from "./missing";
+import type { Options } from "./types";
+
+const options: Options = { verbose: true };
+console.log(a, x, options);
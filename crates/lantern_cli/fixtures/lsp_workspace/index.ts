@@ -0,0 +1,4 @@
+import { a } from "./a";
+import { x } from "./missing";
+
+console.log(a, x);
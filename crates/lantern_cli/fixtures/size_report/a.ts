@@ -0,0 +1,4 @@
+import { c } from "./c";
+import { d } from "./d";
+
+export const a = c + d;
@@ -0,0 +1,3 @@
+import { dep } from "./node_modules/dep/index";
+
+export const d = dep("a fairly long string so d and its dependency weigh more than the rest");
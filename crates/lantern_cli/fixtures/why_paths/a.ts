@@ -0,0 +1,5 @@
+import { heavy } from "./heavy";
+
+export function a() {
+  return heavy;
+}
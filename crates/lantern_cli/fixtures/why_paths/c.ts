@@ -0,0 +1,6 @@
+import { heavy } from "./heavy";
+import { chart } from "./packages/charts/index";
+
+export function c() {
+  return heavy + chart;
+}
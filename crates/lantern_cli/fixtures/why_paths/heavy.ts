@@ -0,0 +1 @@
+export const heavy = 1;
@@ -0,0 +1,5 @@
+import { a } from "./a";
+import { b } from "./b";
+
+a();
+b();
@@ -0,0 +1 @@
+export const chart = "chart";
@@ -0,0 +1,186 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use clap::ValueEnum;
+use color_eyre::eyre::Result;
+
+use lantern_dependency_graph::dump::LanternDump;
+use lantern_dependency_graph::LanternFileDependencyMap;
+
+use crate::commands::affected::get_affected::{
+    get_affected, get_affected_by_removed_file, AffectedFile,
+};
+use crate::commands::affected::symbol_impact::{get_affected_by_symbols, get_changed_exports};
+use crate::commands::affected::test_files::TestFileMatcher;
+use crate::commands::affected::unified_diff::parse_unified_diff;
+use crate::commands::edge_annotation::display_path;
+use crate::commands::source::SourceOptions;
+
+pub const DEFAULT_TEST_PATTERNS: [&str; 3] = ["*.test.*", "*.spec.*", "__tests__/**"];
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum AffectedFormat {
+    /// One affected file per line
+    Lines,
+    /// Affected files with the import chain leading to the changed file
+    Json,
+}
+
+pub struct AffectedOptions {
+    /// Git revision to compute changed files against.
+    pub since: Option<String>,
+    /// Unified diff file to read changed files and lines from.
+    pub diff: Option<PathBuf>,
+    /// Only follow imports of the exports touched by the changed lines.
+    pub symbols: bool,
+    /// Graph snapshot taken before the change, used to find former dependents of
+    /// files that are no longer part of the graph.
+    pub base_snapshot: Option<PathBuf>,
+    pub write_snapshot: Option<PathBuf>,
+    /// Directory snapshot paths are relative to, defaults to the current directory.
+    pub root: Option<PathBuf>,
+    pub all_dependents: bool,
+    /// Report test files depending on the changed files instead of entries.
+    pub tests: bool,
+    pub test_patterns: Vec<String>,
+    pub format: AffectedFormat,
+    /// Revision to read files from.
+    pub source: SourceOptions,
+}
+
+pub fn run(entry_points: &[PathBuf], changed: &[PathBuf], options: &AffectedOptions) -> Result<()> {
+    let root = match &options.root {
+        Some(root) => root.canonicalize()?,
+        None => std::env::current_dir()?.canonicalize()?,
+    };
+
+    let mut entry_points = entry_points.to_vec();
+    let test_matcher = if options.tests {
+        let matcher = TestFileMatcher::new(&root, &options.test_patterns)?;
+        entry_points.extend(matcher.find_test_files());
+        Some(matcher)
+    } else {
+        None
+    };
+
+    let ln_map = options.source.build_symbols_map(&entry_points)?;
+    let mut depgraph = LanternFileDependencyMap::new(ln_map);
+    depgraph.build_dependency_graph();
+
+    if let Some(snapshot_path) = &options.write_snapshot {
+        LanternDump::from_dependency_map(&mut depgraph, &root).save(snapshot_path)?;
+    }
+
+    let base_snapshot = match &options.base_snapshot {
+        Some(snapshot_path) => Some(LanternDump::load(snapshot_path)?),
+        None => None,
+    };
+
+    // Changed files with the changed line ranges when they are known, files without
+    // ranges are treated as changed as a whole.
+    let mut changes: BTreeMap<PathBuf, Option<Vec<(usize, usize)>>> = BTreeMap::new();
+    for changed_file_path in changed {
+        changes.insert(to_absolute_path(changed_file_path)?, None);
+    }
+    if let Some(since) = &options.since {
+        let cwd = std::env::current_dir()?;
+        let git_changes = lantern_git::get_changed_files(&cwd, since)?;
+        for changed_file_path in git_changes.all() {
+            changes.insert(changed_file_path, None);
+        }
+        if options.symbols {
+            for (changed_file_path, lines) in lantern_git::get_changed_lines(&cwd, since)? {
+                changes.insert(changed_file_path, Some(lines));
+            }
+        }
+    }
+    if let Some(diff_path) = &options.diff {
+        let diff = std::fs::read_to_string(diff_path)?;
+        for (changed_file_path, lines) in parse_unified_diff(&diff)? {
+            let changed_file_path = to_absolute_path(&root.join(changed_file_path))?;
+            changes.insert(changed_file_path, options.symbols.then_some(lines));
+        }
+    }
+
+    let mut all_affected: BTreeMap<PathBuf, AffectedFile> = BTreeMap::new();
+
+    // Test files aren't necessarily reachable from the entries, so every dependent is
+    // collected and filtered afterwards.
+    let entries_only = !options.all_dependents && test_matcher.is_none();
+    for (changed_file_path, lines) in &changes {
+        let module_id = depgraph
+            .symbols_map
+            .get_module_id(changed_file_path.to_str().unwrap());
+        let affected = if let Some(module_id) = module_id {
+            if options.symbols {
+                let changed_exports = get_changed_exports(&mut depgraph, module_id, lines.as_ref());
+                get_affected_by_symbols(
+                    &depgraph,
+                    changed_file_path,
+                    changed_exports,
+                    entries_only,
+                )?
+            } else {
+                get_affected(&depgraph, changed_file_path, entries_only)?
+            }
+        } else if let Some(snapshot) = &base_snapshot {
+            let relative_path = changed_file_path
+                .strip_prefix(&root)
+                .unwrap_or(changed_file_path);
+            let dependents = snapshot
+                .get_dependents(relative_path)
+                .iter()
+                .map(|path| root.join(path))
+                .collect::<Vec<PathBuf>>();
+            get_affected_by_removed_file(&depgraph, changed_file_path, &dependents, entries_only)?
+        } else {
+            if !changed_file_path.exists() {
+                eprintln!(
+                    "{} no longer exists, pass --base-snapshot to find its former dependents",
+                    display_path(changed_file_path)
+                );
+            }
+            Vec::new()
+        };
+        for file in affected {
+            // Keep the shortest chain when a file is affected by several changes.
+            let is_shorter = all_affected
+                .get(&file.path)
+                .is_none_or(|existing| file.chain.len() < existing.chain.len());
+            if is_shorter {
+                all_affected.insert(file.path.clone(), file);
+            }
+        }
+    }
+
+    let all_affected = all_affected
+        .into_values()
+        .filter(|file| {
+            test_matcher
+                .as_ref()
+                .is_none_or(|matcher| matcher.is_match(&file.path))
+        })
+        .collect::<Vec<AffectedFile>>();
+    match options.format {
+        AffectedFormat::Lines => {
+            for file in &all_affected {
+                println!("{}", file.path.display());
+            }
+        }
+        AffectedFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&all_affected)?);
+        }
+    }
+
+    return Ok(());
+}
+
+/// Deleted files can't be canonicalized, those are only made absolute.
+fn to_absolute_path(path: &Path) -> Result<PathBuf> {
+    if let Ok(path) = path.canonicalize() {
+        return Ok(path);
+    }
+    return Ok(std::path::absolute(path)?);
+}
@@ -0,0 +1,142 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    path::{Path, PathBuf},
+};
+
+use color_eyre::eyre::Result;
+use serde::Serialize;
+
+use lantern_dependency_graph::LanternFileDependencyMap;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AffectedFile {
+    pub path: PathBuf,
+    /// Changed file that made this file affected.
+    pub changed: PathBuf,
+    /// Import chain from the affected file down to the changed file, both included.
+    pub chain: Vec<PathBuf>,
+}
+
+pub fn get_affected(
+    depgraph: &LanternFileDependencyMap,
+    changed_file_path: &Path,
+    entries_only: bool,
+) -> Result<Vec<AffectedFile>> {
+    let changed_module_id = if let Some(module_id) = depgraph
+        .symbols_map
+        .get_module_id(changed_file_path.to_str().unwrap())
+    {
+        module_id
+    } else {
+        return Ok(Vec::new());
+    };
+
+    // Module id -> module it was reached from, the changed module has no parent.
+    let mut parents: HashMap<usize, Option<usize>> = HashMap::from([(changed_module_id, None)]);
+    let mut order = vec![changed_module_id];
+    let mut queue = VecDeque::from([changed_module_id]);
+
+    while let Some(cur) = queue.pop_front() {
+        let mut dependents = depgraph
+            .inverse_dependency_map
+            .get(&cur)
+            .unwrap_or(&HashSet::new())
+            .iter()
+            .copied()
+            .collect::<Vec<usize>>();
+        dependents.sort();
+
+        for from in dependents {
+            if parents.contains_key(&from) {
+                continue;
+            }
+            parents.insert(from, Some(cur));
+            order.push(from);
+            queue.push_back(from);
+        }
+    }
+
+    return Ok(to_affected_files(
+        depgraph,
+        changed_file_path,
+        &order,
+        &parents,
+        entries_only,
+    ));
+}
+
+/// Turns modules reached from a changed module into affected files. `order` lists the
+/// modules in the order they were reached, starting with the changed module, and
+/// `parents` maps each of them to the module it was reached from.
+pub fn to_affected_files(
+    depgraph: &LanternFileDependencyMap,
+    changed_file_path: &Path,
+    order: &[usize],
+    parents: &HashMap<usize, Option<usize>>,
+    entries_only: bool,
+) -> Vec<AffectedFile> {
+    let mut affected = Vec::new();
+    for module_id in order {
+        let module = depgraph.symbols_map.get_module(*module_id).unwrap();
+        // The changed file itself is only reported when it's an entry.
+        let is_changed_file = parents[module_id].is_none();
+        if !module.is_entry && (entries_only || is_changed_file) {
+            continue;
+        }
+
+        let mut chain = Vec::new();
+        let mut cur = Some(*module_id);
+        while let Some(id) = cur {
+            chain.push(depgraph.symbols_map.get_module_path(id).clone());
+            cur = parents[&id];
+        }
+
+        affected.push(AffectedFile {
+            path: module.file_path.clone(),
+            changed: changed_file_path.to_path_buf(),
+            chain,
+        });
+    }
+
+    return affected;
+}
+
+/// Finds files affected by a file that is no longer part of the graph, e.g. because it
+/// was deleted or renamed, starting from the modules that depended on it before.
+pub fn get_affected_by_removed_file(
+    depgraph: &LanternFileDependencyMap,
+    removed_file_path: &Path,
+    previous_dependents: &[PathBuf],
+    entries_only: bool,
+) -> Result<Vec<AffectedFile>> {
+    let mut affected = Vec::new();
+
+    for dependent in previous_dependents {
+        let module = if let Some(module) = depgraph
+            .symbols_map
+            .get_module_id(dependent.to_str().unwrap())
+            .and_then(|module_id| depgraph.symbols_map.get_module(module_id))
+        {
+            module
+        } else {
+            continue;
+        };
+
+        // get_affected only reports its starting point when it's an entry.
+        if !entries_only && !module.is_entry {
+            affected.push(AffectedFile {
+                path: dependent.clone(),
+                changed: removed_file_path.to_path_buf(),
+                chain: vec![dependent.clone(), removed_file_path.to_path_buf()],
+            });
+        }
+
+        for mut file in get_affected(depgraph, dependent, entries_only)? {
+            file.changed = removed_file_path.to_path_buf();
+            file.chain.push(removed_file_path.to_path_buf());
+            affected.push(file);
+        }
+    }
+
+    return Ok(affected);
+}
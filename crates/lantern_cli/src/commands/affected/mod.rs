@@ -0,0 +1,8 @@
+pub mod command;
+mod get_affected;
+mod symbol_impact;
+mod test_files;
+mod unified_diff;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,154 @@
+use std::{
+    collections::{hash_map::Entry, HashMap, HashSet, VecDeque},
+    path::Path,
+};
+
+use color_eyre::eyre::Result;
+
+use lantern_dependency_graph::LanternFileDependencyMap;
+use lantern_symbols_map::symbol::LNSymbolData;
+
+use crate::commands::affected::get_affected::{to_affected_files, AffectedFile};
+
+/// Names of exports whose declarations intersect the changed lines. A change outside of
+/// any export declaration (imports, local helpers, etc.) may affect every export, so the
+/// whole module is treated as changed. Without line ranges the whole module is changed.
+pub fn get_changed_exports(
+    depgraph: &mut LanternFileDependencyMap,
+    module_id: usize,
+    changed_lines: Option<&Vec<(usize, usize)>>,
+) -> HashSet<String> {
+    let ln_map = &mut depgraph.symbols_map;
+    let changed_lines = if let Some(changed_lines) = changed_lines {
+        changed_lines
+    } else {
+        return ln_map.get_export_names(module_id).into_iter().collect();
+    };
+
+    let mut declarations = Vec::new();
+    for symbol_id in ln_map.modules[module_id].symbols.clone() {
+        let symbol = ln_map.symbols[symbol_id].clone();
+        let names = match &symbol.symbol {
+            LNSymbolData::ExportAll(file_ref) => ln_map.get_export_names(file_ref.module_id),
+            _ => match symbol.get_export_name() {
+                Some(name) => vec![name.to_owned()],
+                None => continue,
+            },
+        };
+        let span = *ln_map.get_declaration_span(symbol_id);
+        declarations.push((ln_map.get_line_range_from_span(module_id, &span), names));
+    }
+
+    let mut changed_exports = HashSet::new();
+    for (start, end) in changed_lines {
+        let mut is_inside_export = false;
+        for ((decl_start, decl_end), names) in &declarations {
+            if start <= decl_end && end >= decl_start {
+                is_inside_export = true;
+                changed_exports.extend(names.iter().cloned());
+            }
+        }
+        if !is_inside_export {
+            return ln_map.get_export_names(module_id).into_iter().collect();
+        }
+    }
+
+    return changed_exports;
+}
+
+/// Finds files affected by a change to some exports of a module. The change only spreads
+/// to modules importing one of the changed names, modules re-exporting them pass the
+/// change on under the re-exported name, while modules consuming them are considered
+/// changed as a whole.
+pub fn get_affected_by_symbols(
+    depgraph: &LanternFileDependencyMap,
+    changed_file_path: &Path,
+    changed_exports: HashSet<String>,
+    entries_only: bool,
+) -> Result<Vec<AffectedFile>> {
+    let ln_map = &depgraph.symbols_map;
+    let changed_module_id =
+        if let Some(module_id) = ln_map.get_module_id(changed_file_path.to_str().unwrap()) {
+            module_id
+        } else {
+            return Ok(Vec::new());
+        };
+
+    let mut parents: HashMap<usize, Option<usize>> = HashMap::from([(changed_module_id, None)]);
+    let mut order = vec![changed_module_id];
+    let mut changed_names: HashMap<usize, HashSet<String>> =
+        HashMap::from([(changed_module_id, changed_exports.clone())]);
+    let mut queue = VecDeque::from([(changed_module_id, changed_exports)]);
+
+    while let Some((cur, names)) = queue.pop_front() {
+        if names.is_empty() {
+            continue;
+        }
+
+        let mut dependents = depgraph
+            .inverse_dependency_map
+            .get(&cur)
+            .unwrap_or(&HashSet::new())
+            .iter()
+            .copied()
+            .collect::<Vec<usize>>();
+        dependents.sort();
+
+        for from in dependents {
+            let mut is_consumed = false;
+            let mut re_exported = HashSet::new();
+
+            for symbol in depgraph.get_dependency_symbols(from, cur) {
+                match &symbol.symbol {
+                    LNSymbolData::ImportNamed(_, imported, _, _, _) => {
+                        is_consumed |= names.contains(imported);
+                    }
+                    LNSymbolData::ImportDefault(_, _, _, _) => {
+                        is_consumed |= names.contains("default");
+                    }
+                    LNSymbolData::ImportStar(_, _, _, _) => {
+                        is_consumed = true;
+                    }
+                    LNSymbolData::ExportNamed(local, exported, _, Some(_))
+                        if names.contains(local) =>
+                    {
+                        re_exported.insert(exported.clone());
+                    }
+                    LNSymbolData::ExportAll(_) => {
+                        re_exported.extend(names.iter().filter(|n| *n != "default").cloned());
+                    }
+                    _ => {}
+                }
+            }
+
+            if !is_consumed && re_exported.is_empty() {
+                continue;
+            }
+
+            if let Entry::Vacant(entry) = parents.entry(from) {
+                entry.insert(Some(cur));
+                order.push(from);
+            }
+
+            let mut new_names = re_exported;
+            if is_consumed {
+                new_names.extend(ln_map.get_export_names(from));
+            }
+
+            let known_names = changed_names.entry(from).or_default();
+            let new_names = new_names
+                .into_iter()
+                .filter(|name| known_names.insert(name.clone()))
+                .collect::<HashSet<String>>();
+            queue.push_back((from, new_names));
+        }
+    }
+
+    return Ok(to_affected_files(
+        depgraph,
+        changed_file_path,
+        &order,
+        &parents,
+        entries_only,
+    ));
+}
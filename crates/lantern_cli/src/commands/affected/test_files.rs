@@ -0,0 +1,48 @@
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::Result;
+
+use crate::commands::path_matcher::PathMatcher;
+
+/// Matches test files by paths relative to the project root.
+pub struct TestFileMatcher {
+    matcher: PathMatcher,
+}
+
+impl TestFileMatcher {
+    pub fn new(root: &Path, patterns: &Vec<String>) -> Result<Self> {
+        return Ok(Self {
+            matcher: PathMatcher::new(root, patterns)?,
+        });
+    }
+
+    pub fn is_match(&self, path: &Path) -> bool {
+        return self.matcher.is_match(path);
+    }
+
+    /// Test files under the root, skipping `node_modules` and files ignored by git.
+    pub fn find_test_files(&self) -> Vec<PathBuf> {
+        let walker = ignore::WalkBuilder::new(self.matcher.get_root())
+            .filter_entry(|entry| entry.file_name() != "node_modules")
+            .build();
+
+        let mut test_files = Vec::new();
+        for entry in walker.flatten() {
+            let is_file = entry
+                .file_type()
+                .is_some_and(|file_type| file_type.is_file());
+            if is_file && is_source_file(entry.path()) && self.is_match(entry.path()) {
+                test_files.push(entry.path().to_path_buf());
+            }
+        }
+        test_files.sort();
+        return test_files;
+    }
+}
+
+fn is_source_file(path: &Path) -> bool {
+    return matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some("ts" | "tsx" | "js" | "jsx" | "mts" | "cts" | "mjs" | "cjs")
+    );
+}
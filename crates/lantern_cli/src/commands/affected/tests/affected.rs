@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use lantern_testing::load_fixture;
+
+    use crate::commands::affected::get_affected::{get_affected, get_affected_by_removed_file};
+    use crate::commands::test_utils::build_depgraph;
+
+    fn file_names(paths: &[std::path::PathBuf]) -> Vec<String> {
+        return paths
+            .iter()
+            .map(|p| p.file_name().unwrap().to_str().unwrap().to_owned())
+            .collect();
+    }
+
+    #[test]
+    fn entries_only() {
+        let depgraph = build_depgraph("affected_chain", &["index.ts", "other.ts"]);
+        let changed = load_fixture!("affected_chain/c.ts").canonicalize().unwrap();
+        let affected = get_affected(&depgraph, &changed, true).unwrap();
+        let paths = affected.iter().map(|a| a.path.clone()).collect::<Vec<_>>();
+        assert_eq!(file_names(&paths), vec!["other.ts", "index.ts"]);
+    }
+
+    #[test]
+    fn all_dependents() {
+        let depgraph = build_depgraph("affected_chain", &["index.ts", "other.ts"]);
+        let changed = load_fixture!("affected_chain/c.ts").canonicalize().unwrap();
+        let affected = get_affected(&depgraph, &changed, false).unwrap();
+        assert_eq!(affected.len(), 4);
+    }
+
+    #[test]
+    fn reports_chain() {
+        let depgraph = build_depgraph("affected_chain", &["index.ts", "other.ts"]);
+        let changed = load_fixture!("affected_chain/c.ts").canonicalize().unwrap();
+        let affected = get_affected(&depgraph, &changed, true).unwrap();
+        let index = affected
+            .iter()
+            .find(|a| a.path.ends_with("index.ts"))
+            .unwrap();
+        assert_eq!(
+            file_names(&index.chain),
+            vec!["index.ts", "a.ts", "b.ts", "c.ts"]
+        );
+    }
+
+    #[test]
+    fn changed_entry() {
+        let depgraph = build_depgraph("affected_chain", &["index.ts", "other.ts"]);
+        let changed = load_fixture!("affected_chain/index.ts")
+            .canonicalize()
+            .unwrap();
+        let affected = get_affected(&depgraph, &changed, true).unwrap();
+        assert_eq!(affected.len(), 1);
+        assert_eq!(file_names(&affected[0].chain), vec!["index.ts"]);
+    }
+
+    #[test]
+    fn removed_file() {
+        let depgraph = build_depgraph("affected_chain", &["index.ts", "other.ts"]);
+        let removed = load_fixture!("affected_chain/removed.ts");
+        let dependents = vec![load_fixture!("affected_chain/b.ts").canonicalize().unwrap()];
+
+        let affected =
+            get_affected_by_removed_file(&depgraph, &removed, &dependents, true).unwrap();
+        assert_eq!(affected.len(), 1);
+        assert_eq!(
+            file_names(&affected[0].chain),
+            vec!["index.ts", "a.ts", "b.ts", "removed.ts"]
+        );
+
+        let affected =
+            get_affected_by_removed_file(&depgraph, &removed, &dependents, false).unwrap();
+        let paths = affected.iter().map(|a| a.path.clone()).collect::<Vec<_>>();
+        assert_eq!(file_names(&paths), vec!["b.ts", "a.ts", "index.ts"]);
+    }
+}
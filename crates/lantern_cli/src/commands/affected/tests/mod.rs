@@ -0,0 +1,4 @@
+mod affected;
+mod symbol_impact;
+mod test_files;
+mod unified_diff;
@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use lantern_testing::load_fixture;
+
+    use crate::commands::affected::symbol_impact::{get_affected_by_symbols, get_changed_exports};
+    use crate::commands::test_utils::build_depgraph;
+
+    fn get_affected_names(lines: Option<&Vec<(usize, usize)>>) -> Vec<String> {
+        let mut depgraph =
+            build_depgraph("affected_symbols", &["app_a.ts", "app_b.ts", "app_c.ts"]);
+        let changed = load_fixture!("affected_symbols/utils/helpers.ts")
+            .canonicalize()
+            .unwrap();
+        let module_id = depgraph
+            .symbols_map
+            .get_module_id(changed.to_str().unwrap())
+            .unwrap();
+        let changed_exports = get_changed_exports(&mut depgraph, module_id, lines);
+        let affected = get_affected_by_symbols(&depgraph, &changed, changed_exports, true).unwrap();
+        let mut names = affected
+            .iter()
+            .map(|a| a.path.file_name().unwrap().to_str().unwrap().to_owned())
+            .collect::<Vec<String>>();
+        names.sort();
+        return names;
+    }
+
+    #[test]
+    fn changed_exports() {
+        let mut depgraph =
+            build_depgraph("affected_symbols", &["app_a.ts", "app_b.ts", "app_c.ts"]);
+        let changed = load_fixture!("affected_symbols/utils/helpers.ts")
+            .canonicalize()
+            .unwrap();
+        let module_id = depgraph
+            .symbols_map
+            .get_module_id(changed.to_str().unwrap())
+            .unwrap();
+        assert_eq!(
+            get_changed_exports(&mut depgraph, module_id, Some(&vec![(6, 6)])),
+            HashSet::from(["common".to_owned()])
+        );
+        // A change between declarations may affect every export.
+        assert_eq!(
+            get_changed_exports(&mut depgraph, module_id, Some(&vec![(4, 4)])).len(),
+            2
+        );
+    }
+
+    #[test]
+    fn only_consumers_of_changed_export() {
+        assert_eq!(get_affected_names(Some(&vec![(1, 3)])), vec!["app_a.ts"]);
+    }
+
+    #[test]
+    fn whole_file_change() {
+        assert_eq!(get_affected_names(None), vec!["app_a.ts", "app_b.ts"]);
+    }
+}
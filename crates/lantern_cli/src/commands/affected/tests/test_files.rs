@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use lantern_dependency_graph::LanternFileDependencyMap;
+    use lantern_symbols_map::build_symbols_map;
+    use lantern_testing::load_fixture;
+
+    use crate::commands::affected::command::DEFAULT_TEST_PATTERNS;
+    use crate::commands::affected::get_affected::get_affected;
+    use crate::commands::affected::test_files::TestFileMatcher;
+
+    fn build_matcher() -> (TestFileMatcher, PathBuf) {
+        let root = load_fixture!("affected_tests").canonicalize().unwrap();
+        let patterns = DEFAULT_TEST_PATTERNS.map(String::from).to_vec();
+        return (TestFileMatcher::new(&root, &patterns).unwrap(), root);
+    }
+
+    fn relative_paths(paths: &[PathBuf], root: &Path) -> Vec<String> {
+        return paths
+            .iter()
+            .map(|p| p.strip_prefix(root).unwrap().to_str().unwrap().to_owned())
+            .collect();
+    }
+
+    #[test]
+    fn finds_test_files() {
+        let (matcher, root) = build_matcher();
+        assert_eq!(
+            relative_paths(&matcher.find_test_files(), &root),
+            vec![
+                "src/__tests__/math.ts",
+                "src/calc.test.ts",
+                "src/format.spec.ts"
+            ]
+        );
+    }
+
+    #[test]
+    fn custom_patterns() {
+        let root = load_fixture!("affected_tests").canonicalize().unwrap();
+        let matcher = TestFileMatcher::new(&root, &vec!["*.spec.ts".to_owned()]).unwrap();
+        assert!(matcher.is_match(&root.join("src/format.spec.ts")));
+        assert!(!matcher.is_match(&root.join("src/calc.test.ts")));
+    }
+
+    #[test]
+    fn tests_depending_on_changed_file() {
+        let (matcher, root) = build_matcher();
+        let sm = build_symbols_map(&matcher.find_test_files()).unwrap();
+        let mut depgraph = LanternFileDependencyMap::new(sm);
+        depgraph.build_dependency_graph();
+
+        let changed = root.join("src/math.ts");
+        let mut tests = get_affected(&depgraph, &changed, false)
+            .unwrap()
+            .into_iter()
+            .map(|a| a.path)
+            .filter(|path| matcher.is_match(path))
+            .collect::<Vec<PathBuf>>();
+        tests.sort();
+        assert_eq!(
+            relative_paths(&tests, &root),
+            vec!["src/__tests__/math.ts", "src/calc.test.ts"]
+        );
+    }
+}
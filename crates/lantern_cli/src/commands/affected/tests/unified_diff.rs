@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::commands::affected::unified_diff::parse_unified_diff;
+
+    #[test]
+    fn changed_lines() {
+        let diff = "\
+diff --git a/src/a.ts b/src/a.ts
+--- a/src/a.ts
++++ b/src/a.ts
+@@ -1,4 +1,4 @@
+ const a = 1;
+-const b = 2;
++const b = 20;
+ const c = 3;
+-const d = 4;
++const d = 40;
+@@ -10,2 +10,1 @@ function f() {
+ const e = 5;
+-const f = 6;
+diff --git a/src/b.ts b/src/b.ts
+deleted file mode 100644
+--- a/src/b.ts
++++ /dev/null
+@@ -1 +0,0 @@
+-export const B = 1;
+";
+        let changed_lines = parse_unified_diff(diff).unwrap();
+        assert_eq!(changed_lines.len(), 2);
+        assert_eq!(
+            changed_lines[&PathBuf::from("src/a.ts")],
+            vec![(2, 2), (4, 4), (10, 10)]
+        );
+        assert_eq!(changed_lines[&PathBuf::from("src/b.ts")], vec![]);
+    }
+
+    #[test]
+    fn invalid_hunk_header() {
+        let diff = "--- a/a.ts\n+++ b/a.ts\n@@ -x +y @@\n";
+        assert!(parse_unified_diff(diff).is_err());
+    }
+}
@@ -0,0 +1,148 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use color_eyre::eyre::{eyre, Result};
+
+/// Parses a unified diff (e.g. the output of `git diff`) into 1-based inclusive ranges of
+/// changed lines in the new version of each file. Lines that were only removed are
+/// attributed to the line preceding them, deleted files map to no ranges.
+///
+/// Paths are returned as written in the diff, without the `a/` and `b/` prefixes.
+pub fn parse_unified_diff(diff: &str) -> Result<HashMap<PathBuf, Vec<(usize, usize)>>> {
+    let mut changed_lines: HashMap<PathBuf, Vec<(usize, usize)>> = HashMap::new();
+    let mut current_file: Option<PathBuf> = None;
+    let mut old_file: Option<PathBuf> = None;
+    let mut hunk: Option<Hunk> = None;
+
+    for line in diff.lines() {
+        if let Some(current) = &mut hunk {
+            let ranges = current_file
+                .as_ref()
+                .map(|path| changed_lines.entry(path.clone()).or_default());
+            current.read_line(line, ranges);
+            if current.is_done() {
+                hunk = None;
+            }
+            continue;
+        }
+
+        if let Some(path) = line.strip_prefix("--- ") {
+            old_file = parse_file_path(path, "a/");
+        } else if let Some(path) = line.strip_prefix("+++ ") {
+            current_file = parse_file_path(path, "b/");
+            if current_file.is_none() {
+                if let Some(old_file) = &old_file {
+                    changed_lines.insert(old_file.clone(), Vec::new());
+                }
+            }
+        } else if let Some(header) = line.strip_prefix("@@ ") {
+            hunk = Some(
+                Hunk::parse(header)
+                    .ok_or_else(|| eyre!("Couldn't parse hunk header {:?}", line))?,
+            );
+        }
+    }
+
+    return Ok(changed_lines);
+}
+
+// "b/src/a.ts\t2024-01-01" -> "src/a.ts", "/dev/null" -> None
+fn parse_file_path(path: &str, prefix: &str) -> Option<PathBuf> {
+    let path = path.split('\t').next().unwrap_or(path).trim();
+    if path == "/dev/null" {
+        return None;
+    }
+    return Some(PathBuf::from(path.strip_prefix(prefix).unwrap_or(path)));
+}
+
+struct Hunk {
+    /// Line number in the new file of the next context or added line.
+    new_line: usize,
+    old_remaining: usize,
+    new_remaining: usize,
+    /// Removed lines that weren't replaced by added lines.
+    pending_removal: bool,
+}
+
+impl Hunk {
+    // "-1,3 +1,4 @@ fn main()"
+    fn parse(header: &str) -> Option<Self> {
+        let mut parts = header.split(' ');
+        let (_, old_count) = parse_range(parts.next()?.strip_prefix('-')?)?;
+        let (new_start, new_count) = parse_range(parts.next()?.strip_prefix('+')?)?;
+        return Some(Self {
+            // Hunks without new lines point at the line preceding the removal.
+            new_line: if new_count == 0 {
+                new_start + 1
+            } else {
+                new_start
+            },
+            old_remaining: old_count,
+            new_remaining: new_count,
+            pending_removal: false,
+        });
+    }
+
+    fn read_line(&mut self, line: &str, ranges: Option<&mut Vec<(usize, usize)>>) {
+        let mut ranges = ranges;
+
+        if line.starts_with('-') {
+            self.old_remaining = self.old_remaining.saturating_sub(1);
+            self.pending_removal = true;
+        } else if line.starts_with('+') {
+            if let Some(ranges) = ranges.as_deref_mut() {
+                add_line(ranges, self.new_line);
+            }
+            self.new_line += 1;
+            self.new_remaining = self.new_remaining.saturating_sub(1);
+            self.pending_removal = false;
+        } else if line.starts_with('\\') {
+            // "\ No newline at end of file"
+            return;
+        } else {
+            self.flush_removal(ranges.as_deref_mut());
+            self.new_line += 1;
+            self.old_remaining = self.old_remaining.saturating_sub(1);
+            self.new_remaining = self.new_remaining.saturating_sub(1);
+        }
+
+        if self.is_done() {
+            self.flush_removal(ranges);
+        }
+    }
+
+    fn flush_removal(&mut self, ranges: Option<&mut Vec<(usize, usize)>>) {
+        if !self.pending_removal {
+            return;
+        }
+        if let Some(ranges) = ranges {
+            add_line(ranges, self.new_line.max(2) - 1);
+        }
+        self.pending_removal = false;
+    }
+
+    fn is_done(&self) -> bool {
+        return self.old_remaining == 0 && self.new_remaining == 0;
+    }
+}
+
+// "12,3" -> (12, 3), "12" -> (12, 1)
+fn parse_range(range: &str) -> Option<(usize, usize)> {
+    let mut parts = range.split(',');
+    let start = parts.next()?.parse().ok()?;
+    let count = match parts.next() {
+        Some(count) => count.parse().ok()?,
+        None => 1,
+    };
+    return Some((start, count));
+}
+
+// Extends the last range when lines are adjacent to keep the list short.
+fn add_line(ranges: &mut Vec<(usize, usize)>, line: usize) {
+    if let Some(last) = ranges.last_mut() {
+        if line >= last.0 && line <= last.1 + 1 {
+            last.1 = last.1.max(line);
+            return;
+        }
+    }
+    ranges.push((line, line));
+}
@@ -0,0 +1,179 @@
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use serde::{Deserialize, Serialize};
+
+use lantern_dependency_graph::LanternFileDependencyMap;
+use lantern_symbols_map::symbol::{LNSymbol, LNSymbolData};
+use lantern_symbols_map::symbols_map::LNSymbolsMap;
+
+/// Findings that were already present when the baseline was recorded. Keys only use
+/// paths relative to the baseline file and symbol names, so they survive unrelated edits
+/// that move spans around or renumber modules.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Baseline {
+    pub version: u32,
+    /// "<path>#<export name>" of every unused export.
+    #[serde(default)]
+    pub unused_exports: BTreeSet<String>,
+    /// Sorted paths of the files of each group of files importing each other, the strongly
+    /// connected components of the graph.
+    #[serde(default)]
+    pub cycles: BTreeSet<Vec<String>>,
+}
+
+pub struct BaselineOptions {
+    pub path: PathBuf,
+    /// Record the current findings instead of reporting the new ones.
+    pub update: bool,
+}
+
+impl Baseline {
+    pub const VERSION: u32 = 1;
+
+    pub fn new() -> Self {
+        return Self {
+            version: Self::VERSION,
+            unused_exports: BTreeSet::new(),
+            cycles: BTreeSet::new(),
+        };
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path).wrap_err_with(|| {
+            format!(
+                "Couldn't read baseline {:?}, run with --update-baseline to create it",
+                path
+            )
+        })?;
+        let baseline: Self = serde_json::from_str(&content)
+            .wrap_err_with(|| format!("Couldn't parse baseline {:?}", path))?;
+        if baseline.version != Self::VERSION {
+            return Err(eyre!(
+                "Unsupported baseline version {} in {:?}, expected {}",
+                baseline.version,
+                path,
+                Self::VERSION
+            ));
+        }
+        return Ok(baseline);
+    }
+
+    /// Same as `load`, but starts from an empty baseline when the file doesn't exist yet.
+    pub fn load_or_new(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::new());
+        }
+        return Self::load(path);
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, content + "\n")
+            .wrap_err_with(|| format!("Couldn't write baseline {:?}", path))?;
+        return Ok(());
+    }
+}
+
+pub fn get_baseline_options(path: &Option<PathBuf>, update: bool) -> Option<BaselineOptions> {
+    return path.as_ref().map(|path| BaselineOptions {
+        path: path.clone(),
+        update,
+    });
+}
+
+/// Directory baseline keys are relative to, the one containing the baseline file.
+pub fn get_baseline_root(path: &Path) -> Result<PathBuf> {
+    let path = std::path::absolute(path)?;
+    return Ok(path.parent().unwrap().canonicalize()?);
+}
+
+/// Leaves out findings whose keys are in the baseline. Returns the remaining findings and
+/// the baseline keys that weren't found anymore.
+pub fn apply_baseline<T, K: Ord + Clone>(
+    findings: Vec<T>,
+    keys: Vec<K>,
+    baseline: &BTreeSet<K>,
+) -> (Vec<T>, Vec<K>) {
+    let current = keys.iter().cloned().collect::<BTreeSet<K>>();
+    let fixed = baseline.difference(&current).cloned().collect();
+    let new = findings
+        .into_iter()
+        .zip(keys)
+        .filter(|(_, key)| !baseline.contains(key))
+        .map(|(finding, _)| finding)
+        .collect();
+    return (new, fixed);
+}
+
+/// Cyclic components are baselined when a recorded component contains all of their files,
+/// so a group that lost files keeps matching while one that gained files, or merged with
+/// another, is new. Returns whether each key is baselined and the recorded components no
+/// current one falls within anymore.
+pub fn apply_cycle_baseline(
+    keys: &[Vec<String>],
+    baseline: &BTreeSet<Vec<String>>,
+) -> (Vec<bool>, Vec<Vec<String>>) {
+    let matches = |key: &Vec<String>, recorded: &Vec<String>| {
+        return key.iter().all(|path| recorded.contains(path));
+    };
+    let baselined = keys
+        .iter()
+        .map(|key| baseline.iter().any(|recorded| matches(key, recorded)))
+        .collect();
+    let fixed = baseline
+        .iter()
+        .filter(|recorded| !keys.iter().any(|key| matches(key, recorded)))
+        .cloned()
+        .collect();
+    return (baselined, fixed);
+}
+
+pub fn print_fixed_entries(fixed: &[String]) {
+    if fixed.is_empty() {
+        return;
+    }
+    println!("Fixed since the baseline was recorded, run with --update-baseline to remove:");
+    for key in fixed {
+        println!("  {}", key);
+    }
+    println!();
+}
+
+/// "<path>#<export name>", re-exported modules are named "* from <path>".
+pub fn export_key(ln_map: &LNSymbolsMap, symbol: &LNSymbol, root: &Path) -> String {
+    let name = match &symbol.symbol {
+        LNSymbolData::ExportAll(file_ref) => {
+            format!("* from {}", relative_path(ln_map, file_ref.module_id, root))
+        }
+        _ => symbol
+            .get_export_name()
+            .or(symbol.get_name())
+            .unwrap_or("*")
+            .to_string(),
+    };
+    return format!("{}#{}", relative_path(ln_map, symbol.module_id, root), name);
+}
+
+/// Sorted relative paths of the files of a cycle or cyclic component.
+pub fn cycle_key(depgraph: &LanternFileDependencyMap, cycle: &[usize], root: &Path) -> Vec<String> {
+    let mut key = cycle
+        .iter()
+        .map(|module_id| relative_path(&depgraph.symbols_map, *module_id, root))
+        .collect::<Vec<String>>();
+    key.sort();
+    return key;
+}
+
+pub fn relative_path(ln_map: &LNSymbolsMap, module_id: usize, root: &Path) -> String {
+    let path = ln_map.get_module_path(module_id);
+    return path
+        .strip_prefix(root)
+        .unwrap_or(path)
+        .display()
+        .to_string();
+}
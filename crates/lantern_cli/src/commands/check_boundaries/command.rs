@@ -0,0 +1,73 @@
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::Result;
+
+use lantern_dependency_graph::LanternFileDependencyMap;
+use lantern_formatters::findings_reporter::LanternSeverity;
+
+use crate::commands::check_boundaries::rules::BoundaryRules;
+use crate::commands::edge_annotation::annotate_edge_with_note;
+use crate::commands::findings::BOUNDARY_VIOLATION_RULE;
+use crate::commands::source::SourceOptions;
+
+/// Returns severities of the reported violations.
+pub fn run(
+    entry_points: &[PathBuf],
+    config: &Path,
+    root: &Option<PathBuf>,
+    source: &SourceOptions,
+) -> Result<Vec<LanternSeverity>> {
+    let mut rules = BoundaryRules::load(config)?;
+    // Paths in the config are relative to the config file unless a root is given.
+    let root = match root {
+        Some(root) => root.canonicalize()?,
+        None => config.canonicalize()?.parent().unwrap().to_path_buf(),
+    };
+
+    let ln_map = source.build_symbols_map(entry_points)?;
+    let mut depgraph = LanternFileDependencyMap::new(ln_map);
+    depgraph.build_dependency_graph();
+
+    let violations = find_violations(&depgraph, &mut rules, &root)?;
+    for (from, to, message) in &violations {
+        if let Some(edge) = annotate_edge_with_note(&mut depgraph, *from, *to, Some(message)) {
+            println!("{}", edge.annotation.print());
+            println!();
+        }
+    }
+
+    println!("Total boundary violations found: {}", violations.len());
+
+    return Ok(vec![BOUNDARY_VIOLATION_RULE.severity; violations.len()]);
+}
+
+/// Imports forbidden by the rules as (from, to, message), sorted by module ids.
+pub fn find_violations(
+    depgraph: &LanternFileDependencyMap,
+    rules: &mut BoundaryRules,
+    root: &Path,
+) -> Result<Vec<(usize, usize, String)>> {
+    let relative_path = |module_id: usize| {
+        let path = depgraph.symbols_map.get_module_path(module_id);
+        return path
+            .strip_prefix(root)
+            .unwrap_or(path)
+            .display()
+            .to_string();
+    };
+
+    let mut edges = depgraph
+        .dependency_map
+        .iter()
+        .flat_map(|(from, to)| to.iter().map(|to| (*from, *to)))
+        .collect::<Vec<(usize, usize)>>();
+    edges.sort();
+
+    let mut violations = Vec::new();
+    for (from, to) in edges {
+        if let Some(violation) = rules.check(&relative_path(from), &relative_path(to))? {
+            violations.push((from, to, violation.message));
+        }
+    }
+    return Ok(violations);
+}
@@ -0,0 +1,5 @@
+pub mod command;
+mod rules;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,194 @@
+use std::{collections::HashMap, path::Path};
+
+use color_eyre::eyre::{Result, WrapErr};
+use regex::{Captures, Regex};
+use serde::Deserialize;
+
+/// Boundaries config, e.g.
+///
+/// ```json
+/// {
+///   "rules": [
+///     { "from": "domain/**", "disallow": ["ui/**"], "message": "domain must not depend on ui" },
+///     { "from": "features/{feature}/**", "disallow": ["features/*/internal/**"] },
+///     { "from": "features/{feature}/**", "allow": ["features/{feature}/**"] }
+///   ]
+/// }
+/// ```
+///
+/// Globs match paths relative to the root. `{name}` in `from` captures a path segment,
+/// which `allow` and `disallow` globs can refer to.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BoundariesConfig {
+    pub rules: Vec<BoundaryRule>,
+    /// Verdict for imports no rule applies to.
+    #[serde(default)]
+    pub default: BoundaryVerdict,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BoundaryRule {
+    pub from: String,
+    #[serde(default)]
+    pub allow: Vec<String>,
+    #[serde(default)]
+    pub disallow: Vec<String>,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BoundaryVerdict {
+    #[default]
+    Allow,
+    Disallow,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct BoundaryViolation {
+    /// Index of the rule that forbids the import, none when it's forbidden by default.
+    pub rule: Option<usize>,
+    pub message: String,
+}
+
+pub struct BoundaryRules {
+    config: BoundariesConfig,
+    from_patterns: Vec<Regex>,
+    /// Compiled `allow` and `disallow` globs after captures are substituted.
+    to_patterns: HashMap<String, Regex>,
+}
+
+impl BoundaryRules {
+    pub fn new(config: BoundariesConfig) -> Result<Self> {
+        let mut from_patterns = Vec::new();
+        for rule in &config.rules {
+            let pattern = glob_to_regex(&rule.from, None);
+            from_patterns.push(
+                Regex::new(&pattern)
+                    .wrap_err_with(|| format!("Invalid boundary rule {:?}", rule.from))?,
+            );
+        }
+
+        return Ok(Self {
+            config,
+            from_patterns,
+            to_patterns: HashMap::new(),
+        });
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .wrap_err_with(|| format!("Couldn't read boundaries config {:?}", path))?;
+        let config = serde_json::from_str(&content)
+            .wrap_err_with(|| format!("Couldn't parse boundaries config {:?}", path))?;
+        return Self::new(config);
+    }
+
+    /// Checks an import between two paths relative to the root. Every rule matching the
+    /// importing file is applied in order, the last one with a verdict wins.
+    pub fn check(&mut self, from: &str, to: &str) -> Result<Option<BoundaryViolation>> {
+        let mut verdict = (self.config.default, None);
+        for (idx, rule) in self.config.rules.iter().enumerate() {
+            let captures = if let Some(captures) = self.from_patterns[idx].captures(from) {
+                captures
+            } else {
+                continue;
+            };
+
+            if matches_any(&mut self.to_patterns, &rule.disallow, &captures, to)? {
+                verdict = (BoundaryVerdict::Disallow, Some(idx));
+            } else if matches_any(&mut self.to_patterns, &rule.allow, &captures, to)? {
+                verdict = (BoundaryVerdict::Allow, Some(idx));
+            }
+        }
+
+        return Ok(match verdict {
+            (BoundaryVerdict::Allow, _) => None,
+            (BoundaryVerdict::Disallow, Some(idx)) => {
+                let rule = &self.config.rules[idx];
+                Some(BoundaryViolation {
+                    rule: Some(idx),
+                    message: match &rule.message {
+                        Some(message) => message.clone(),
+                        None => format!("forbidden by rule {} ({})", idx + 1, rule.from),
+                    },
+                })
+            }
+            (BoundaryVerdict::Disallow, None) => Some(BoundaryViolation {
+                rule: None,
+                message: "not allowed by any rule".to_owned(),
+            }),
+        });
+    }
+}
+
+fn matches_any(
+    cache: &mut HashMap<String, Regex>,
+    globs: &[String],
+    captures: &Captures,
+    path: &str,
+) -> Result<bool> {
+    for glob in globs {
+        let pattern = glob_to_regex(glob, Some(captures));
+        if !cache.contains_key(&pattern) {
+            let regex = Regex::new(&pattern)
+                .wrap_err_with(|| format!("Invalid boundary rule {:?}", glob))?;
+            cache.insert(pattern.clone(), regex);
+        }
+        if cache[&pattern].is_match(path) {
+            return Ok(true);
+        }
+    }
+    return Ok(false);
+}
+
+// "features/{feature}/**" -> "^features/(?P<feature>[^/]+)(?:/.*)?$". With captures,
+// `{name}` is replaced by the captured segment instead, unknown names match any segment.
+fn glob_to_regex(glob: &str, captures: Option<&Captures>) -> String {
+    let mut regex = String::from("^");
+    let chars = glob.trim_start_matches("./").chars().collect::<Vec<char>>();
+    let mut idx = 0;
+    while idx < chars.len() {
+        match chars[idx] {
+            '*' if chars.get(idx + 1) == Some(&'*') => {
+                let is_segment_start = idx == 0 || chars[idx - 1] == '/';
+                if is_segment_start && chars.get(idx + 2) == Some(&'/') {
+                    // "**/" matches any number of leading directories, including none.
+                    regex.push_str("(?:.*/)?");
+                    idx += 3;
+                    continue;
+                }
+                if idx > 0 && chars[idx - 1] == '/' && idx + 2 == chars.len() {
+                    // "dir/**" also matches "dir" itself.
+                    regex.pop();
+                    regex.push_str("(?:/.*)?");
+                } else {
+                    regex.push_str(".*");
+                }
+                idx += 2;
+                continue;
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            '{' => {
+                if let Some(len) = chars[idx..].iter().position(|c| *c == '}') {
+                    let name = chars[idx + 1..idx + len].iter().collect::<String>();
+                    match captures.map(|captures| captures.name(&name)) {
+                        None => regex.push_str(&format!("(?P<{}>[^/]+)", name)),
+                        Some(Some(value)) => regex.push_str(&regex::escape(value.as_str())),
+                        Some(None) => regex.push_str("[^/]+"),
+                    }
+                    idx += len + 1;
+                    continue;
+                }
+                regex.push_str(&regex::escape("{"));
+            }
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+        idx += 1;
+    }
+    regex.push('$');
+    return regex;
+}
@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::commands::check_boundaries::command::find_violations;
+    use crate::commands::check_boundaries::rules::BoundaryRules;
+    use crate::commands::test_utils::{build_depgraph, fixture_root};
+
+    #[test]
+    fn violations() {
+        let depgraph = build_depgraph("boundaries", &["index.ts"]);
+        let root = fixture_root("boundaries");
+        let mut rules = BoundaryRules::load(&root.join("lantern-boundaries.json")).unwrap();
+        let mut violations = find_violations(&depgraph, &mut rules, &root)
+            .unwrap()
+            .iter()
+            .map(|(from, to, message)| {
+                let path = |id: usize| {
+                    let path = depgraph.symbols_map.get_module_path(id);
+                    path.strip_prefix(&root).unwrap().display().to_string()
+                };
+                (path(*from), path(*to), message.clone())
+            })
+            .collect::<Vec<_>>();
+        violations.sort();
+
+        assert_eq!(
+            violations,
+            vec![
+                (
+                    "domain/user.ts".to_owned(),
+                    "ui/theme.ts".to_owned(),
+                    "domain must not depend on ui".to_owned()
+                ),
+                (
+                    "features/cart/index.ts".to_owned(),
+                    "features/auth/internal/session.ts".to_owned(),
+                    "forbidden by rule 2 (features/{feature}/**)".to_owned()
+                ),
+            ]
+        );
+    }
+}
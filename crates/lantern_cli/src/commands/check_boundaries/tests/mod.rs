@@ -0,0 +1,2 @@
+mod check_boundaries;
+mod rules;
@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+    use crate::commands::check_boundaries::rules::{BoundariesConfig, BoundaryRules};
+
+    fn build_rules(config: &str) -> BoundaryRules {
+        let config: BoundariesConfig = serde_json::from_str(config).unwrap();
+        return BoundaryRules::new(config).unwrap();
+    }
+
+    fn is_allowed(rules: &mut BoundaryRules, from: &str, to: &str) -> bool {
+        return rules.check(from, to).unwrap().is_none();
+    }
+
+    #[test]
+    fn disallowed_layers() {
+        let mut rules =
+            build_rules(r#"{ "rules": [{ "from": "domain/**", "disallow": ["ui/**"] }] }"#);
+        assert!(!is_allowed(&mut rules, "domain/user.ts", "ui/theme.ts"));
+        assert!(!is_allowed(&mut rules, "domain/a/b.ts", "ui/c/d.ts"));
+        assert!(is_allowed(&mut rules, "ui/theme.ts", "domain/user.ts"));
+        assert!(is_allowed(&mut rules, "src/domain/user.ts", "ui/theme.ts"));
+    }
+
+    #[test]
+    fn captures_and_last_rule_wins() {
+        let mut rules = build_rules(
+            r#"{ "rules": [
+                { "from": "features/{feature}/**", "disallow": ["features/*/internal/**"] },
+                { "from": "features/{feature}/**", "allow": ["features/{feature}/**"] }
+            ] }"#,
+        );
+        assert!(is_allowed(
+            &mut rules,
+            "features/cart/index.ts",
+            "features/cart/internal/store.ts"
+        ));
+        assert!(!is_allowed(
+            &mut rules,
+            "features/cart/index.ts",
+            "features/auth/internal/session.ts"
+        ));
+        assert!(is_allowed(
+            &mut rules,
+            "features/cart/index.ts",
+            "features/auth/index.ts"
+        ));
+    }
+
+    #[test]
+    fn default_verdict() {
+        let mut rules = build_rules(
+            r#"{ "default": "disallow", "rules": [{ "from": "ui/**", "allow": ["**/*.ts"] }] }"#,
+        );
+        assert!(is_allowed(&mut rules, "ui/button.ts", "domain/user.ts"));
+        let violation = rules
+            .check("domain/user.ts", "ui/theme.ts")
+            .unwrap()
+            .unwrap();
+        assert_eq!(violation.rule, None);
+    }
+
+    #[test]
+    fn unknown_fields() {
+        let config = r#"{ "rules": [{ "from": "a/**", "disalow": ["b/**"] }] }"#;
+        assert!(serde_json::from_str::<BoundariesConfig>(config).is_err());
+    }
+}
@@ -0,0 +1,210 @@
+use std::{collections::HashSet, path::PathBuf};
+
+use color_eyre::eyre::Result;
+
+use lantern_dependency_graph::graph::{LanternGraph, LanternGraphGrouping};
+use lantern_dependency_graph::LanternFileDependencyMap;
+use lantern_formatters::findings_reporter::{LanternFinding, LanternSeverity};
+
+use crate::commands::baseline::{
+    apply_cycle_baseline, cycle_key, get_baseline_root, print_fixed_entries, Baseline,
+    BaselineOptions,
+};
+use crate::commands::cycles::find_cycles::{
+    find_cycles, find_cycles_in_graph, find_cyclic_components,
+};
+use crate::commands::cycles::suggest::suggest_edges_to_remove;
+use crate::commands::edge_annotation::annotate_edge;
+use crate::commands::findings::{cycle_finding, get_reporter, ReportFormat, CYCLE_RULE};
+use crate::commands::grouping::{get_grouping, GroupBy};
+use crate::commands::source::SourceOptions;
+
+pub struct CyclesOptions {
+    pub format: ReportFormat,
+    /// Suggest the smallest set of imports to remove to make the graph acyclic.
+    pub suggest: bool,
+    /// Find cycles between groups of files instead of files.
+    pub group_by: Option<GroupBy>,
+    pub depth: Option<usize>,
+    /// Directory group names are relative to, defaults to the current directory.
+    pub root: Option<PathBuf>,
+    /// Only report file cycles missing from this baseline.
+    pub baseline: Option<BaselineOptions>,
+    /// Revision to read files from.
+    pub source: SourceOptions,
+}
+
+/// Returns severities of the reported cycles.
+pub fn run(entry_points: &[PathBuf], options: &CyclesOptions) -> Result<Vec<LanternSeverity>> {
+    let ln_map = options.source.build_symbols_map(entry_points)?;
+    let mut depgraph = LanternFileDependencyMap::new(ln_map);
+    depgraph.build_dependency_graph();
+
+    if let Some(grouping) = get_grouping(options.group_by, options.depth) {
+        let root = match &options.root {
+            Some(root) => root.canonicalize()?,
+            None => std::env::current_dir()?.canonicalize()?,
+        };
+        let total = print_group_cycles(&depgraph, &root, grouping);
+        return Ok(vec![CYCLE_RULE.severity; total]);
+    }
+
+    let mut cycles = find_cycles(&depgraph);
+
+    let mut fixed = Vec::new();
+    let mut baselined = 0;
+    if let Some(baseline_options) = &options.baseline {
+        // Cycles found by the search depend on module order, the components they're part
+        // of don't.
+        let root = get_baseline_root(&baseline_options.path)?;
+        let components = find_cyclic_components(&depgraph);
+        let keys = components
+            .iter()
+            .map(|component| cycle_key(&depgraph, component, &root))
+            .collect::<Vec<Vec<String>>>();
+
+        if baseline_options.update {
+            let mut baseline = Baseline::load_or_new(&baseline_options.path)?;
+            baseline.cycles = keys.into_iter().collect();
+            baseline.save(&baseline_options.path)?;
+            println!(
+                "Baseline updated with {} groups of cyclic files",
+                baseline.cycles.len()
+            );
+            return Ok(Vec::new());
+        }
+
+        let baseline = Baseline::load(&baseline_options.path)?;
+        let (is_baselined, fixed_components) = apply_cycle_baseline(&keys, &baseline.cycles);
+        let baselined_modules = components
+            .iter()
+            .zip(is_baselined)
+            .filter(|(_, is_baselined)| *is_baselined)
+            .flat_map(|(component, _)| component.iter().copied())
+            .collect::<HashSet<usize>>();
+        let total = cycles.len();
+        cycles.retain(|cycle| !baselined_modules.contains(&cycle[0]));
+        baselined = total - cycles.len();
+        fixed = fixed_components
+            .iter()
+            .map(|component| component.join(", "))
+            .collect::<Vec<String>>();
+    }
+    let severities = vec![CYCLE_RULE.severity; cycles.len()];
+
+    if options.suggest {
+        print_suggestions(&mut depgraph);
+        return Ok(severities);
+    }
+
+    let root = std::env::current_dir()?.canonicalize()?;
+    if let Some(reporter) = get_reporter(options.format, &root) {
+        let findings = cycles
+            .iter()
+            .filter_map(|cycle| cycle_finding(&mut depgraph, cycle, &root))
+            .collect::<Vec<LanternFinding>>();
+        println!("{}", reporter.report(&findings));
+        return Ok(severities);
+    }
+
+    for (idx, cycle) in cycles.iter().enumerate() {
+        let mut hops = Vec::new();
+        let mut annotations = Vec::new();
+
+        for (pos, from) in cycle.iter().enumerate() {
+            let to = cycle[(pos + 1) % cycle.len()];
+            if let Some(edge) = annotate_edge(&mut depgraph, *from, to) {
+                hops.push(edge.summary);
+                annotations.push(edge.annotation);
+            }
+        }
+
+        println!("Cycle {}: {}", idx + 1, hops.join(" → "));
+        println!();
+        for annotation in &annotations {
+            println!("{}", annotation.print());
+            println!();
+        }
+    }
+
+    print_fixed_entries(&fixed);
+    println!("Total cycles found: {}", cycles.len());
+    if baselined > 0 {
+        println!("Cycles in the baseline: {}", baselined);
+    }
+
+    return Ok(severities);
+}
+
+fn print_group_cycles(
+    depgraph: &LanternFileDependencyMap,
+    root: &std::path::Path,
+    grouping: LanternGraphGrouping,
+) -> usize {
+    let graph = LanternGraph::from_dependency_map(depgraph, root).group(grouping);
+    let cycles = find_cycles_in_graph(graph.nodes.len(), &graph.get_dependency_map());
+    let name = |id: usize| graph.nodes[id].path.display().to_string();
+
+    for (idx, cycle) in cycles.iter().enumerate() {
+        let mut hops = cycle.iter().map(|id| name(*id)).collect::<Vec<String>>();
+        hops.push(name(cycle[0]));
+        println!("Cycle {}: {}", idx + 1, hops.join(" → "));
+
+        for (pos, from) in cycle.iter().enumerate() {
+            let to = cycle[(pos + 1) % cycle.len()];
+            let edge = graph
+                .edges
+                .iter()
+                .find(|edge| edge.from == *from && edge.to == to)
+                .unwrap();
+            println!(
+                "  {} → {}, file dependencies: {}",
+                name(*from),
+                name(to),
+                edge.weight
+            );
+        }
+        println!();
+    }
+
+    println!("Total cycles found: {}", cycles.len());
+
+    return cycles.len();
+}
+
+fn print_suggestions(depgraph: &mut LanternFileDependencyMap) {
+    let suggestions = suggest_edges_to_remove(depgraph);
+    let total_symbols: usize = suggestions.iter().map(|s| s.symbols).sum();
+
+    let mut printed = 0;
+    for suggestion in &suggestions {
+        let edge = if let Some(edge) = annotate_edge(depgraph, suggestion.from, suggestion.to) {
+            edge
+        } else {
+            continue;
+        };
+
+        printed += 1;
+        println!(
+            "{}. {} ({} symbols, breaks {}{} cycles)",
+            printed,
+            edge.summary,
+            suggestion.symbols,
+            if suggestion.is_lower_bound {
+                "at least "
+            } else {
+                ""
+            },
+            suggestion.cycles
+        );
+        println!();
+        println!("{}", edge.annotation.print());
+        println!();
+    }
+
+    println!(
+        "Removing {} imports ({} symbols) makes the graph acyclic",
+        suggestions.len(),
+        total_symbols
+    );
+}
@@ -0,0 +1,78 @@
+use std::collections::{HashMap, HashSet};
+
+use lantern_dependency_graph::algorithms::strongly_connected_components::cyclic_components;
+use lantern_dependency_graph::LanternFileDependencyMap;
+
+/// Cycles found by a depth first search. Every file in a cycle is reported, but not every
+/// cycle, and which ones depends on module order. Use `find_cyclic_components` when the
+/// result needs to be stable.
+pub fn find_cycles(depgraph: &LanternFileDependencyMap) -> Vec<Vec<usize>> {
+    return find_cycles_in_graph(depgraph.symbols_map.modules.len(), &depgraph.dependency_map);
+}
+
+/// Groups of files importing each other, directly or not.
+pub fn find_cyclic_components(depgraph: &LanternFileDependencyMap) -> Vec<Vec<usize>> {
+    return cyclic_components(depgraph.symbols_map.modules.len(), &depgraph.dependency_map);
+}
+
+/// Same as `find_cycles` for any graph with nodes numbered from 0 to `nodes`.
+pub fn find_cycles_in_graph(
+    nodes: usize,
+    dependency_map: &HashMap<usize, HashSet<usize>>,
+) -> Vec<Vec<usize>> {
+    let mut visited: Vec<u8> = vec![0; nodes];
+    let mut cycles: Vec<Vec<usize>> = Vec::new();
+
+    fn dfs(
+        dep_map: &HashMap<usize, HashSet<usize>>,
+        visited: &mut Vec<u8>,
+        cycles: &mut Vec<Vec<usize>>,
+        path: &mut Vec<usize>,
+        module_id: usize,
+    ) {
+        if visited[module_id] == 2 {
+            return;
+        }
+
+        if visited[module_id] == 1 {
+            let cycle_index = path.iter().position(|el| *el == module_id).unwrap();
+            cycles.push(path[cycle_index..].to_vec());
+            return;
+        }
+
+        visited[module_id] = 1;
+        path.push(module_id);
+
+        let mut dependencies = dep_map
+            .get(&module_id)
+            .unwrap_or(&HashSet::new())
+            .iter()
+            .copied()
+            .collect::<Vec<usize>>();
+        dependencies.sort();
+
+        for to in dependencies {
+            dfs(dep_map, visited, cycles, path, to);
+        }
+
+        visited[module_id] = 2;
+        path.pop();
+    }
+
+    for module_id in 0..nodes {
+        if visited[module_id] == 2 {
+            continue;
+        }
+
+        let mut path = Vec::new();
+        dfs(
+            dependency_map,
+            &mut visited,
+            &mut cycles,
+            &mut path,
+            module_id,
+        );
+    }
+
+    return cycles;
+}
@@ -0,0 +1,6 @@
+pub mod command;
+pub mod find_cycles;
+mod suggest;
+
+#[cfg(test)]
+mod tests;
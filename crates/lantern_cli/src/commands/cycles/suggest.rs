@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use lantern_dependency_graph::algorithms::elementary_cycles::elementary_cycles;
+use lantern_dependency_graph::algorithms::feedback_arc_set::feedback_arc_set;
+use lantern_dependency_graph::LanternFileDependencyMap;
+
+#[derive(Debug)]
+pub struct EdgeSuggestion {
+    pub from: usize,
+    pub to: usize,
+    /// Number of imported or re-exported symbols carried by the edge.
+    pub symbols: usize,
+    /// Number of cycles, visiting each file at most once, that go through the edge.
+    pub cycles: usize,
+    /// More than `CYCLE_COUNT_LIMIT` cycles exist, `cycles` only counts the ones found
+    /// before the search stopped.
+    pub is_lower_bound: bool,
+}
+
+/// Cycles counted before giving up, dense groups of files can have millions.
+pub const CYCLE_COUNT_LIMIT: usize = 100_000;
+
+/// Finds a small set of imports whose removal makes the graph acyclic, edges are
+/// weighted by the number of symbols they carry. Suggestions breaking the most cycles
+/// come first, cheaper edges win ties.
+pub fn suggest_edges_to_remove(depgraph: &LanternFileDependencyMap) -> Vec<EdgeSuggestion> {
+    let nodes = depgraph.symbols_map.modules.len();
+    let cycles = elementary_cycles(nodes, &depgraph.dependency_map, CYCLE_COUNT_LIMIT);
+    let mut cycles_per_edge: HashMap<(usize, usize), usize> = HashMap::new();
+    for cycle in &cycles.cycles {
+        for (pos, from) in cycle.iter().enumerate() {
+            let to = cycle[(pos + 1) % cycle.len()];
+            *cycles_per_edge.entry((*from, to)).or_default() += 1;
+        }
+    }
+
+    let arcs = feedback_arc_set(nodes, &depgraph.dependency_map, |from, to| {
+        depgraph.get_dependency_symbols(from, to).len()
+    });
+
+    let mut suggestions = arcs
+        .into_iter()
+        .map(|(from, to)| EdgeSuggestion {
+            from,
+            to,
+            symbols: depgraph.get_dependency_symbols(from, to).len(),
+            cycles: cycles_per_edge.get(&(from, to)).copied().unwrap_or(0),
+            is_lower_bound: cycles.is_truncated,
+        })
+        .collect::<Vec<EdgeSuggestion>>();
+
+    suggestions.sort_by(|a, b| {
+        return b
+            .cycles
+            .cmp(&a.cycles)
+            .then(a.symbols.cmp(&b.symbols))
+            .then(a.from.cmp(&b.from))
+            .then(a.to.cmp(&b.to));
+    });
+
+    return suggestions;
+}
@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::path::Path;
+
+    use lantern_dependency_graph::graph::{LanternGraph, LanternGraphGrouping};
+    use lantern_testing::{fixture_project, FIXTURE_PROJECT_ROOT};
+
+    use crate::commands::baseline::{apply_cycle_baseline, cycle_key, BaselineOptions};
+    use crate::commands::cycles::command::{run, CyclesOptions};
+    use crate::commands::cycles::find_cycles::{
+        find_cycles, find_cycles_in_graph, find_cyclic_components,
+    };
+    use crate::commands::edge_annotation::annotate_edge;
+    use crate::commands::findings::{cycle_finding, FailOptions, ReportFormat};
+    use crate::commands::source::SourceOptions;
+    use crate::commands::test_utils::{build_depgraph, build_project_depgraph, fixture_root};
+
+    #[test]
+    fn no_cycles() {
+        let depgraph = build_depgraph("cycles_none", &["index.ts"]);
+        let cycles = find_cycles(&depgraph);
+        assert_eq!(cycles.len(), 0);
+    }
+
+    #[test]
+    fn simple_cycle() {
+        let depgraph = build_depgraph("cycles_simple", &["index.ts"]);
+        let cycles = find_cycles(&depgraph);
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].len(), 2);
+    }
+
+    #[test]
+    fn cycle_as_finding() {
+        let mut depgraph = build_depgraph("cycles_simple", &["index.ts"]);
+        let root = fixture_root("cycles_simple");
+        let cycles = find_cycles(&depgraph);
+        let finding = cycle_finding(&mut depgraph, &cycles[0], &root).unwrap();
+        assert_eq!(finding.rule.id, "import-cycle");
+        assert_eq!(finding.related_locations.len(), 2);
+        assert_eq!(finding.location.start_line, 1);
+        assert_eq!(finding.fingerprint, "a.ts, b.ts");
+    }
+
+    #[test]
+    fn cycle_baseline_key() {
+        let depgraph = build_depgraph("cycles_simple", &["index.ts"]);
+        let root = fixture_root("cycles_simple");
+        let cycles = find_cycles(&depgraph);
+        assert_eq!(
+            cycle_key(&depgraph, &cycles[0], &root),
+            vec!["a.ts", "b.ts"]
+        );
+    }
+
+    #[test]
+    fn cycle_baseline_matches_components() {
+        let depgraph = build_depgraph("cycles_simple", &["index.ts"]);
+        let root = fixture_root("cycles_simple");
+        let keys = find_cyclic_components(&depgraph)
+            .iter()
+            .map(|component| cycle_key(&depgraph, component, &root))
+            .collect::<Vec<Vec<String>>>();
+        assert_eq!(keys, vec![vec!["a.ts", "b.ts"]]);
+
+        let key = |paths: &[&str]| paths.iter().map(|path| path.to_string()).collect();
+        let baseline = BTreeSet::from([key(&["a.ts", "b.ts", "c.ts"]), key(&["x.ts", "y.ts"])]);
+        let (baselined, fixed) = apply_cycle_baseline(&keys, &baseline);
+        assert_eq!(baselined, vec![true]);
+        assert_eq!(fixed, vec![vec!["x.ts", "y.ts"]]);
+
+        let (baselined, fixed) = apply_cycle_baseline(&keys, &BTreeSet::from([key(&["a.ts"])]));
+        assert_eq!(baselined, vec![false]);
+        assert_eq!(fixed, vec![vec!["a.ts"]]);
+        let (baselined, _) = apply_cycle_baseline(&keys, &BTreeSet::from([key(&["b.ts", "c.ts"])]));
+        assert_eq!(baselined, vec![false]);
+    }
+
+    #[test]
+    fn cyclic_components_of_project() {
+        let project = fixture_project! {
+            "index.ts" => "import { a } from './a';\nimport { s } from './self';\nconsole.log(a, s);\n",
+            "a.ts" => "import { b } from './b';\nexport const a = b;\n",
+            "b.ts" => "import { a } from './a';\nexport const b = () => a;\n",
+            "self.ts" => "import { s as t } from './self';\nexport const s = () => t;\n",
+        };
+        let depgraph = build_project_depgraph(&project, &["index.ts"]);
+        let root = Path::new(FIXTURE_PROJECT_ROOT);
+        let keys = find_cyclic_components(&depgraph)
+            .iter()
+            .map(|component| cycle_key(&depgraph, component, root))
+            .collect::<Vec<Vec<String>>>();
+        assert_eq!(keys, vec![vec!["a.ts", "b.ts"], vec!["self.ts"]]);
+    }
+
+    #[test]
+    fn cycle_growing_past_baseline_is_reported() {
+        let dir =
+            std::env::temp_dir().join(format!("lantern_cycle_baseline_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let dir = dir.canonicalize().unwrap();
+        let write = |name: &str, source: &str| std::fs::write(dir.join(name), source).unwrap();
+        write("index.ts", "import { a } from './a';\nconsole.log(a);\n");
+        write("a.ts", "import { b } from './b';\nexport const a = b;\n");
+        write(
+            "b.ts",
+            "import { a } from './a';\nexport const b = () => a;\n",
+        );
+
+        let run_cycles = |update: bool| {
+            let options = CyclesOptions {
+                format: ReportFormat::Text,
+                suggest: false,
+                group_by: None,
+                depth: None,
+                root: None,
+                baseline: Some(BaselineOptions {
+                    path: dir.join("baseline.json"),
+                    update,
+                }),
+                source: SourceOptions::default(),
+            };
+            return run(&[dir.join("index.ts")], &options).unwrap();
+        };
+        let fail = FailOptions {
+            fail_on: None,
+            max_warnings: None,
+        };
+
+        run_cycles(true);
+        assert!(!fail.should_fail(&run_cycles(false)));
+
+        // c.ts joins the recorded a.ts ↔ b.ts cycle.
+        write(
+            "b.ts",
+            "import { c } from './c';\nexport const b = () => c;\n",
+        );
+        write("c.ts", "import { a } from './a';\nexport const c = a;\n");
+        let severities = run_cycles(false);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(fail.should_fail(&severities));
+    }
+
+    #[test]
+    fn re_export_cycle() {
+        let depgraph = build_depgraph("cycles_re_export", &["index.ts"]);
+        let cycles = find_cycles(&depgraph);
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].len(), 2);
+    }
+
+    #[test]
+    fn annotates_cycle_edges() {
+        let mut depgraph = build_depgraph("cycles_re_export", &["index.ts"]);
+        let cycle = find_cycles(&depgraph).remove(0);
+
+        let a_to_b = annotate_edge(&mut depgraph, cycle[0], cycle[1]).unwrap();
+        assert!(a_to_b.summary.contains("a.ts:1 re-exports"));
+        assert!(a_to_b.summary.ends_with("b.ts"));
+
+        let b_to_a = annotate_edge(&mut depgraph, cycle[1], cycle[0]).unwrap();
+        assert!(b_to_a.summary.contains("b.ts:1 imports"));
+        assert!(b_to_a.summary.ends_with("a.ts"));
+    }
+
+    #[test]
+    fn cycles_between_dirs() {
+        let depgraph = build_depgraph("cycles_dirs", &["index.ts"]);
+        assert_eq!(find_cycles(&depgraph).len(), 0);
+
+        let root = fixture_root("cycles_dirs");
+        let graph = LanternGraph::from_dependency_map(&depgraph, &root)
+            .group(LanternGraphGrouping::Dir(None));
+        let cycles = find_cycles_in_graph(graph.nodes.len(), &graph.get_dependency_map());
+        assert_eq!(cycles.len(), 1);
+
+        let mut names = cycles[0]
+            .iter()
+            .map(|id| graph.nodes[*id].path.display().to_string())
+            .collect::<Vec<String>>();
+        names.sort();
+        assert_eq!(names, vec!["features/auth", "shared"]);
+    }
+}
@@ -0,0 +1,2 @@
+mod cycles;
+mod suggest;
@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod tests {
+    use lantern_testing::fixture_project;
+
+    use crate::commands::cycles::suggest::suggest_edges_to_remove;
+    use crate::commands::test_utils::{build_depgraph, build_project_depgraph};
+
+    #[test]
+    fn suggests_edge_with_fewest_symbols() {
+        let depgraph = build_depgraph("cycles_suggest", &["index.ts"]);
+        let suggestions = suggest_edges_to_remove(&depgraph);
+        assert_eq!(suggestions.len(), 1);
+
+        let suggestion = &suggestions[0];
+        let from = depgraph.symbols_map.get_module_path(suggestion.from);
+        let to = depgraph.symbols_map.get_module_path(suggestion.to);
+        assert!(from.ends_with("cycles_suggest/a.ts"));
+        assert!(to.ends_with("cycles_suggest/b.ts"));
+        assert_eq!(suggestion.symbols, 1);
+        assert_eq!(suggestion.cycles, 1);
+        assert!(!suggestion.is_lower_bound);
+    }
+
+    #[test]
+    fn counts_every_cycle_through_an_edge() {
+        // a → b → a and a → b → c → a share a → b.
+        let project = fixture_project! {
+            "index.ts" => "import { a } from './a';\nconsole.log(a);\n",
+            "a.ts" => "import { b } from './b';\nexport const a = b;\n",
+            "b.ts" => "import { a } from './a';\nimport { c } from './c';\nexport const b = () => a + c;\n",
+            "c.ts" => "import { a } from './a';\nexport const c = () => a;\n",
+        };
+        let depgraph = build_project_depgraph(&project, &["index.ts"]);
+        let suggestions = suggest_edges_to_remove(&depgraph);
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].cycles, 2);
+        assert!(depgraph
+            .symbols_map
+            .get_module_path(suggestions[0].from)
+            .ends_with("a.ts"));
+    }
+
+    #[test]
+    fn no_suggestions_without_cycles() {
+        let depgraph = build_depgraph("cycles_none", &["index.ts"]);
+        assert_eq!(suggest_edges_to_remove(&depgraph).len(), 0);
+    }
+}
@@ -0,0 +1,126 @@
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
+
+use clap::ValueEnum;
+use color_eyre::eyre::{eyre, Result};
+
+use lantern_dependency_graph::graph::LanternGraph;
+use lantern_dependency_graph::LanternFileDependencyMap;
+use lantern_formatters::graph_formatter::LanternGraphFormatter;
+use lantern_formatters::graphml::GraphMLFormatter;
+use lantern_formatters::graphviz::graphviz_formatter::GraphvizFormatter;
+use lantern_formatters::html::HtmlFormatter;
+use lantern_formatters::json::JsonFormatter;
+use lantern_formatters::mermaid::MermaidFormatter;
+
+use crate::commands::depgraph::focus::{get_focused_modules, FocusDirection};
+use crate::commands::grouping::{get_grouping, GroupBy};
+use crate::commands::path_matcher::PathMatcher;
+use crate::commands::source::SourceOptions;
+use crate::commands::unused_exports::find_unused_exports::find_unused_exports;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum DepgraphFormat {
+    Graphviz,
+    /// Nodes with path, entry and package attributes, and typed edges
+    Json,
+    /// Mermaid flowchart
+    Mermaid,
+    #[value(name = "graphml")]
+    GraphML,
+    /// Self-contained interactive page with exports, unused exports and cycles
+    Html,
+}
+
+pub struct DepgraphOptions {
+    pub format: DepgraphFormat,
+    /// Directory paths are relative to, defaults to the current directory.
+    pub root: Option<PathBuf>,
+    pub group_by: Option<GroupBy>,
+    /// Number of leading directories to keep when grouping by directory.
+    pub depth: Option<usize>,
+    /// Only output the neighbourhood of this file.
+    pub focus: Option<PathBuf>,
+    /// Number of imports to follow from the focused file.
+    pub focus_depth: Option<usize>,
+    pub direction: FocusDirection,
+    /// Globs of files to leave out of the graph.
+    pub exclude: Vec<String>,
+    /// File to write the graph to instead of stdout.
+    pub output: Option<PathBuf>,
+    /// Revision to read files from.
+    pub source: SourceOptions,
+}
+
+pub fn build(entry_points: &[PathBuf], options: &DepgraphOptions) -> Result<()> {
+    let ln_map = options.source.build_symbols_map(entry_points)?;
+    let mut depgraph = LanternFileDependencyMap::new(ln_map);
+    depgraph.build_dependency_graph();
+
+    let root = match &options.root {
+        Some(root) => root.canonicalize()?,
+        None => std::env::current_dir()?.canonicalize()?,
+    };
+    let mut graph = build_graph(&depgraph, &root)?;
+
+    let exclude = PathMatcher::new(&root, &options.exclude)?;
+    let is_excluded =
+        |module_id: usize| exclude.is_match(depgraph.symbols_map.get_module_path(module_id));
+    if let Some(focus) = &options.focus {
+        let focus_path = depgraph
+            .symbols_map
+            .get_file_system()
+            .canonicalize(&std::env::current_dir()?.join(focus))?;
+        let module_id = depgraph
+            .symbols_map
+            .get_module_id(focus_path.to_str().unwrap())
+            .ok_or_else(|| eyre!("{:?} is not part of the dependency graph", focus))?;
+        let modules = get_focused_modules(
+            &depgraph,
+            module_id,
+            options.focus_depth,
+            options.direction,
+            is_excluded,
+        );
+        graph = graph.subgraph(&modules);
+    } else if !options.exclude.is_empty() {
+        let modules = (0..graph.nodes.len())
+            .filter(|module_id| !is_excluded(*module_id))
+            .collect::<HashSet<usize>>();
+        graph = graph.subgraph(&modules);
+    }
+
+    if let Some(grouping) = get_grouping(options.group_by, options.depth) {
+        graph = graph.group(grouping);
+    }
+
+    let output = get_formatter(options.format).format(&graph);
+    match &options.output {
+        Some(output_path) => std::fs::write(output_path, output)?,
+        None => println!("{}", output),
+    }
+    return Ok(());
+}
+
+/// File level graph with unused exports marked.
+pub fn build_graph(depgraph: &LanternFileDependencyMap, root: &Path) -> Result<LanternGraph> {
+    let mut graph = LanternGraph::from_dependency_map(depgraph, root);
+    for symbol in find_unused_exports(&depgraph.symbols_map)? {
+        if let Some(name) = symbol.get_export_name() {
+            graph.mark_unused_export(symbol.module_id, name);
+        }
+    }
+    return Ok(graph);
+}
+
+pub fn get_formatter(format: DepgraphFormat) -> Box<dyn LanternGraphFormatter> {
+    return match format {
+        DepgraphFormat::Graphviz => Box::new(GraphvizFormatter),
+        DepgraphFormat::Json => Box::new(JsonFormatter),
+        DepgraphFormat::Mermaid => Box::new(MermaidFormatter),
+        DepgraphFormat::GraphML => Box::new(GraphMLFormatter),
+        DepgraphFormat::Html => Box::new(HtmlFormatter),
+    };
+}
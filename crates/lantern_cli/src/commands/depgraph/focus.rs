@@ -0,0 +1,45 @@
+use std::collections::HashSet;
+
+use clap::ValueEnum;
+
+use lantern_dependency_graph::algorithms::neighbourhood::neighbourhood;
+use lantern_dependency_graph::LanternFileDependencyMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum FocusDirection {
+    /// Files the focused file depends on
+    Deps,
+    /// Files depending on the focused file
+    Dependents,
+    Both,
+}
+
+/// Modules within `depth` imports of the focused module. With both directions,
+/// dependencies and dependents are walked separately, so siblings sharing a dependency
+/// aren't included.
+pub fn get_focused_modules<F: Fn(usize) -> bool>(
+    depgraph: &LanternFileDependencyMap,
+    module_id: usize,
+    depth: Option<usize>,
+    direction: FocusDirection,
+    is_excluded: F,
+) -> HashSet<usize> {
+    let mut modules = HashSet::from([module_id]);
+    if direction != FocusDirection::Dependents {
+        modules.extend(neighbourhood(
+            module_id,
+            &depgraph.dependency_map,
+            depth,
+            &is_excluded,
+        ));
+    }
+    if direction != FocusDirection::Deps {
+        modules.extend(neighbourhood(
+            module_id,
+            &depgraph.inverse_dependency_map,
+            depth,
+            &is_excluded,
+        ));
+    }
+    return modules;
+}
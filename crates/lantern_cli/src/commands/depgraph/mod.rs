@@ -0,0 +1,5 @@
+pub mod command;
+pub mod focus;
+
+#[cfg(test)]
+mod tests;
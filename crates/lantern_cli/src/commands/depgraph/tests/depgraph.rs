@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use lantern_dependency_graph::graph::{LanternEdgeKind, LanternGraph};
+
+    use crate::commands::depgraph::command::{build_graph, get_formatter, DepgraphFormat};
+    use crate::commands::test_utils::{build_depgraph, fixture_root};
+
+    fn get_graph() -> LanternGraph {
+        let depgraph = build_depgraph("depgraph_edge_kinds", &["index.ts"]);
+        return build_graph(&depgraph, &fixture_root("depgraph_edge_kinds")).unwrap();
+    }
+
+    fn get_edges(graph: &LanternGraph) -> Vec<(String, String, LanternEdgeKind)> {
+        let path = |id: usize| graph.nodes[id].path.to_str().unwrap().to_owned();
+        let mut edges = graph
+            .edges
+            .iter()
+            .map(|edge| (path(edge.from), path(edge.to), edge.kind))
+            .collect::<Vec<_>>();
+        edges.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+        return edges;
+    }
+
+    #[test]
+    fn edge_kinds() {
+        let graph = get_graph();
+        let edge = |from: &str, to: &str, kind| (from.to_owned(), to.to_owned(), kind);
+        assert_eq!(
+            get_edges(&graph),
+            vec![
+                edge("index.ts", "config.ts", LanternEdgeKind::TypeImport),
+                edge("index.ts", "ui.ts", LanternEdgeKind::Import),
+                edge("render.ts", "config.ts", LanternEdgeKind::Import),
+                edge("ui.ts", "render.ts", LanternEdgeKind::ReExport),
+            ]
+        );
+    }
+
+    #[test]
+    fn relative_paths() {
+        let graph = get_graph();
+        assert_eq!(graph.nodes[0].path.to_str().unwrap(), "index.ts");
+        assert!(graph.nodes[0].is_entry);
+    }
+
+    #[test]
+    fn mermaid() {
+        let graph = get_graph();
+        let output = get_formatter(DepgraphFormat::Mermaid).format(&graph);
+        assert!(output.starts_with("flowchart LR\n  n0[\"index.ts\"]"));
+        assert!(output.contains("n0 -.-> "));
+        assert!(output.contains(" ==> "));
+        assert!(output.ends_with("class n0 entry"));
+    }
+
+    #[test]
+    fn json() {
+        let graph = get_graph();
+        let output = get_formatter(DepgraphFormat::Json).format(&graph);
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(json["nodes"][0]["path"], "index.ts");
+        assert_eq!(json["nodes"][0]["is_entry"], true);
+        assert_eq!(json["edges"].as_array().unwrap().len(), 4);
+        assert!(output.contains("\"kind\": \"type-import\""));
+    }
+
+    #[test]
+    fn graphml() {
+        let graph = get_graph();
+        let output = get_formatter(DepgraphFormat::GraphML).format(&graph);
+        assert!(output.contains(r#"<data key="path">index.ts</data>"#));
+        assert!(output.contains(r#"<data key="kind">re-export</data>"#));
+    }
+
+    #[test]
+    fn unused_exports() {
+        let graph = get_graph();
+        let config = graph
+            .nodes
+            .iter()
+            .find(|node| node.path.ends_with("config.ts"))
+            .unwrap();
+        let exports = config
+            .exports
+            .iter()
+            .map(|export| (export.name.as_str(), export.is_unused))
+            .collect::<Vec<_>>();
+        assert_eq!(exports, vec![("Config", false), ("DEFAULT_DEBUG", true)]);
+    }
+
+    #[test]
+    fn html() {
+        let graph = get_graph();
+        let output = get_formatter(DepgraphFormat::Html).format(&graph);
+        assert!(output.starts_with("<!DOCTYPE html>"));
+        assert!(!output.contains("/*LANTERN_GRAPH_DATA*/"));
+        assert!(output.contains(r#""path":"index.ts""#));
+        assert!(output.contains(r#""DEFAULT_DEBUG""#));
+        assert!(output.contains(r#""isUnused":true"#));
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::commands::depgraph::focus::{get_focused_modules, FocusDirection};
+    use crate::commands::path_matcher::PathMatcher;
+    use crate::commands::test_utils::{build_depgraph, fixture_root};
+
+    fn focus(
+        file: &str,
+        depth: Option<usize>,
+        direction: FocusDirection,
+        exclude: Vec<String>,
+    ) -> Vec<String> {
+        let depgraph = build_depgraph("depgraph_focus", &["index.ts", "other.ts"]);
+        let root = fixture_root("depgraph_focus");
+        let exclude = PathMatcher::new(&root, &exclude).unwrap();
+        let module_id = depgraph
+            .symbols_map
+            .get_module_id(root.join(file).to_str().unwrap())
+            .unwrap();
+        let modules: HashSet<usize> =
+            get_focused_modules(&depgraph, module_id, depth, direction, |id| {
+                exclude.is_match(depgraph.symbols_map.get_module_path(id))
+            });
+
+        let mut paths = modules
+            .iter()
+            .map(|id| {
+                let path = depgraph.symbols_map.get_module_path(*id);
+                path.strip_prefix(&root).unwrap().display().to_string()
+            })
+            .collect::<Vec<String>>();
+        paths.sort();
+        return paths;
+    }
+
+    #[test]
+    fn both_directions() {
+        assert_eq!(
+            focus("b.ts", Some(1), FocusDirection::Both, vec![]),
+            vec!["a.ts", "b.ts", "c.ts", "other.ts"]
+        );
+    }
+
+    #[test]
+    fn dependencies_only() {
+        assert_eq!(
+            focus("b.ts", None, FocusDirection::Deps, vec![]),
+            vec!["b.ts", "c.ts"]
+        );
+    }
+
+    #[test]
+    fn dependents_only() {
+        assert_eq!(
+            focus("c.ts", None, FocusDirection::Dependents, vec![]),
+            vec!["a.ts", "b.ts", "c.ts", "index.ts", "other.ts"]
+        );
+    }
+
+    #[test]
+    fn excluded_files() {
+        assert_eq!(
+            focus(
+                "a.ts",
+                None,
+                FocusDirection::Deps,
+                vec!["vendor/**".to_owned()]
+            ),
+            vec!["a.ts", "b.ts", "c.ts"]
+        );
+    }
+}
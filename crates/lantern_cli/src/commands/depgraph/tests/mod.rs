@@ -0,0 +1,2 @@
+mod depgraph;
+mod focus;
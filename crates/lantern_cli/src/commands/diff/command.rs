@@ -0,0 +1,146 @@
+use std::path::{Path, PathBuf};
+
+use clap::ValueEnum;
+use color_eyre::eyre::{eyre, Result};
+
+use lantern_dependency_graph::dump::LanternDump;
+use lantern_dependency_graph::LanternFileDependencyMap;
+
+use crate::commands::diff::compare::{compare_snapshots, SnapshotDiff};
+use crate::commands::source::SourceOptions;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum DiffFormat {
+    Text,
+    Json,
+}
+
+/// One side of a diff.
+#[derive(Debug, Clone)]
+pub enum DiffSide {
+    /// Files of a git revision.
+    Rev(String),
+    /// Snapshot written by --write-snapshot or a dump.
+    Snapshot(PathBuf),
+    WorkingTree,
+}
+
+pub fn get_diff_side(rev: &Option<String>, snapshot: &Option<PathBuf>) -> DiffSide {
+    if let Some(snapshot) = snapshot {
+        return DiffSide::Snapshot(snapshot.clone());
+    }
+    return match rev {
+        Some(rev) => DiffSide::Rev(rev.clone()),
+        None => DiffSide::WorkingTree,
+    };
+}
+
+pub struct DiffOptions {
+    pub base: DiffSide,
+    pub head: DiffSide,
+    /// Directory snapshot paths are relative to, defaults to the current directory.
+    pub root: Option<PathBuf>,
+    /// File to write the head snapshot to.
+    pub write_snapshot: Option<PathBuf>,
+    pub format: DiffFormat,
+}
+
+pub fn run(entry_points: &[PathBuf], options: &DiffOptions) -> Result<()> {
+    let root = match &options.root {
+        Some(root) => root.canonicalize()?,
+        None => std::env::current_dir()?.canonicalize()?,
+    };
+    let base = get_snapshot(entry_points, &options.base, &root)?;
+    let head = get_snapshot(entry_points, &options.head, &root)?;
+    if let Some(snapshot_path) = &options.write_snapshot {
+        head.save(snapshot_path)?;
+    }
+
+    let diff = compare_snapshots(&base, &head)?;
+    match options.format {
+        DiffFormat::Text => print_diff(&diff),
+        DiffFormat::Json => println!("{}", serde_json::to_string_pretty(&diff)?),
+    }
+
+    return Ok(());
+}
+
+pub fn get_snapshot(entry_points: &[PathBuf], side: &DiffSide, root: &Path) -> Result<LanternDump> {
+    let rev = match side {
+        DiffSide::Snapshot(path) => return LanternDump::load(path),
+        DiffSide::Rev(rev) => Some(rev.clone()),
+        DiffSide::WorkingTree => None,
+    };
+    if entry_points.is_empty() {
+        return Err(eyre!(
+            "Entry points are required to analyse {}",
+            rev.as_deref().unwrap_or("the working tree")
+        ));
+    }
+
+    let source = SourceOptions { rev };
+    let ln_map = source.build_symbols_map(entry_points)?;
+    let mut depgraph = LanternFileDependencyMap::new(ln_map);
+    depgraph.build_dependency_graph();
+    return Ok(LanternDump::from_dependency_map(&mut depgraph, root));
+}
+
+fn print_diff(diff: &SnapshotDiff) {
+    if diff.is_empty() {
+        println!("No changes found");
+        return;
+    }
+
+    let print_section = |title: &str, added: Vec<String>, removed: Vec<String>| {
+        if added.is_empty() && removed.is_empty() {
+            return;
+        }
+        println!("{}:", title);
+        for line in added {
+            println!("  + {}", line);
+        }
+        for line in removed {
+            println!("  - {}", line);
+        }
+        println!();
+    };
+    let format_dependencies = |dependencies: &Vec<(String, String)>| {
+        return dependencies
+            .iter()
+            .map(|(from, to)| format!("{} → {}", from, to))
+            .collect::<Vec<String>>();
+    };
+    let format_cycles = |cycles: &Vec<Vec<String>>| {
+        return cycles
+            .iter()
+            .map(|cycle| cycle.join(", "))
+            .collect::<Vec<String>>();
+    };
+
+    print_section(
+        "Modules",
+        diff.added_modules.clone(),
+        diff.removed_modules.clone(),
+    );
+    print_section(
+        "Dependencies",
+        format_dependencies(&diff.added_dependencies),
+        format_dependencies(&diff.removed_dependencies),
+    );
+    print_section(
+        "Cycles",
+        format_cycles(&diff.added_cycles),
+        format_cycles(&diff.resolved_cycles),
+    );
+    print_section(
+        "Unused exports",
+        diff.added_unused_exports.clone(),
+        diff.resolved_unused_exports.clone(),
+    );
+    print_section(
+        "Entry exports",
+        diff.added_entry_exports.clone(),
+        diff.removed_entry_exports.clone(),
+    );
+    println!("Total changes found: {}", diff.get_change_count());
+}
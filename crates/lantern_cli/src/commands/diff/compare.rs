@@ -0,0 +1,162 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    path::Path,
+    sync::Arc,
+};
+
+use color_eyre::eyre::Result;
+use serde::Serialize;
+
+use lantern_dependency_graph::algorithms::strongly_connected_components::cyclic_components;
+use lantern_dependency_graph::dump::LanternDump;
+use lantern_dependency_graph::LanternFileDependencyMap;
+use lantern_fs::MemoryFileSystem;
+
+use crate::commands::baseline::export_key;
+use crate::commands::unused_exports::find_unused_exports::find_unused_exports;
+
+/// Everything that changed between two snapshots, sorted. Paths are relative to the
+/// snapshots' root.
+#[derive(Debug, Default, Serialize)]
+pub struct SnapshotDiff {
+    pub added_modules: Vec<String>,
+    pub removed_modules: Vec<String>,
+    /// Pairs of (from, to).
+    pub added_dependencies: Vec<(String, String)>,
+    pub removed_dependencies: Vec<(String, String)>,
+    /// Sorted paths of groups of files importing each other, the strongly connected
+    /// components of the graph. A group that gained or lost files is both resolved and
+    /// added.
+    pub added_cycles: Vec<Vec<String>>,
+    pub resolved_cycles: Vec<Vec<String>>,
+    pub added_unused_exports: Vec<String>,
+    pub resolved_unused_exports: Vec<String>,
+    /// Entry exports as "path#name".
+    pub added_entry_exports: Vec<String>,
+    pub removed_entry_exports: Vec<String>,
+}
+
+impl SnapshotDiff {
+    pub fn is_empty(&self) -> bool {
+        return self.get_change_count() == 0;
+    }
+
+    pub fn get_change_count(&self) -> usize {
+        return self.added_modules.len()
+            + self.removed_modules.len()
+            + self.added_dependencies.len()
+            + self.removed_dependencies.len()
+            + self.added_cycles.len()
+            + self.resolved_cycles.len()
+            + self.added_unused_exports.len()
+            + self.resolved_unused_exports.len()
+            + self.added_entry_exports.len()
+            + self.removed_entry_exports.len();
+    }
+}
+
+pub fn compare_snapshots(base: &LanternDump, head: &LanternDump) -> Result<SnapshotDiff> {
+    let (added_modules, removed_modules) = compare_sets(get_modules(base), get_modules(head));
+    let (added_dependencies, removed_dependencies) =
+        compare_sets(get_dependencies(base), get_dependencies(head));
+
+    let base_graph = to_dependency_map(base)?;
+    let head_graph = to_dependency_map(head)?;
+    let (added_unused_exports, resolved_unused_exports) = compare_sets(
+        get_unused_exports(&base_graph)?,
+        get_unused_exports(&head_graph)?,
+    );
+    let (added_entry_exports, removed_entry_exports) = compare_sets(
+        get_entry_exports(&base_graph),
+        get_entry_exports(&head_graph),
+    );
+
+    let (added_cycles, resolved_cycles) = compare_sets(get_cycles(base), get_cycles(head));
+
+    return Ok(SnapshotDiff {
+        added_modules,
+        removed_modules,
+        added_dependencies,
+        removed_dependencies,
+        added_cycles,
+        resolved_cycles,
+        added_unused_exports,
+        resolved_unused_exports,
+        added_entry_exports,
+        removed_entry_exports,
+    });
+}
+
+// Returns (added, removed).
+fn compare_sets<T: Ord + Clone>(base: BTreeSet<T>, head: BTreeSet<T>) -> (Vec<T>, Vec<T>) {
+    let added = head.difference(&base).cloned().collect();
+    let removed = base.difference(&head).cloned().collect();
+    return (added, removed);
+}
+
+fn get_path(snapshot: &LanternDump, module_id: usize) -> String {
+    return snapshot.modules[module_id].path.display().to_string();
+}
+
+fn get_modules(snapshot: &LanternDump) -> BTreeSet<String> {
+    return (0..snapshot.modules.len())
+        .map(|module_id| get_path(snapshot, module_id))
+        .collect();
+}
+
+fn get_dependencies(snapshot: &LanternDump) -> BTreeSet<(String, String)> {
+    return snapshot
+        .dependencies
+        .iter()
+        .map(|(from, to)| (get_path(snapshot, *from), get_path(snapshot, *to)))
+        .collect();
+}
+
+// Module paths stay relative to the dump's root, the sources are never read.
+fn to_dependency_map(snapshot: &LanternDump) -> Result<LanternFileDependencyMap> {
+    return snapshot.to_dependency_map(Path::new(""), Arc::new(MemoryFileSystem::new()));
+}
+
+fn get_unused_exports(depgraph: &LanternFileDependencyMap) -> Result<BTreeSet<String>> {
+    let ln_map = &depgraph.symbols_map;
+    return Ok(find_unused_exports(ln_map)?
+        .iter()
+        .map(|symbol| export_key(ln_map, symbol, Path::new("")))
+        .collect());
+}
+
+fn get_entry_exports(depgraph: &LanternFileDependencyMap) -> BTreeSet<String> {
+    let ln_map = &depgraph.symbols_map;
+    return ln_map
+        .modules
+        .iter()
+        .enumerate()
+        .filter(|(_, module)| module.is_entry)
+        .flat_map(|(module_id, module)| {
+            return ln_map
+                .get_export_names(module_id)
+                .into_iter()
+                .map(move |name| format!("{}#{}", module.file_path.display(), name));
+        })
+        .collect();
+}
+
+// Module ids differ between snapshots, cyclic components are compared by their paths.
+fn get_cycles(snapshot: &LanternDump) -> BTreeSet<Vec<String>> {
+    let mut dependency_map: HashMap<usize, HashSet<usize>> = HashMap::new();
+    for (from, to) in &snapshot.dependencies {
+        dependency_map.entry(*from).or_default().insert(*to);
+    }
+
+    return cyclic_components(snapshot.modules.len(), &dependency_map)
+        .iter()
+        .map(|component| {
+            let mut paths = component
+                .iter()
+                .map(|module_id| get_path(snapshot, *module_id))
+                .collect::<Vec<String>>();
+            paths.sort();
+            return paths;
+        })
+        .collect();
+}
@@ -0,0 +1,5 @@
+pub mod command;
+mod compare;
+
+#[cfg(test)]
+mod tests;
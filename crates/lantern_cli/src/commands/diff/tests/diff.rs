@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use lantern_dependency_graph::dump::{LanternDump, LanternDumpModule};
+    use lantern_testing::load_fixture;
+
+    use crate::commands::diff::command::{get_snapshot, DiffSide};
+    use crate::commands::diff::compare::compare_snapshots;
+
+    fn get_fixture_snapshot(name: &str) -> LanternDump {
+        let root = load_fixture!(name).canonicalize().unwrap();
+        return get_snapshot(&[root.join("index.ts")], &DiffSide::WorkingTree, &root).unwrap();
+    }
+
+    fn pair(from: &str, to: &str) -> (String, String) {
+        return (from.to_string(), to.to_string());
+    }
+
+    #[test]
+    fn snapshot_diff() {
+        let base = get_fixture_snapshot("diff_base");
+        let head = get_fixture_snapshot("diff_head");
+        let diff = compare_snapshots(&base, &head).unwrap();
+
+        assert!(diff.added_modules.is_empty());
+        assert_eq!(diff.removed_modules, vec!["removed.ts"]);
+        assert_eq!(diff.added_dependencies, vec![pair("b.ts", "a.ts")]);
+        assert_eq!(
+            diff.removed_dependencies,
+            vec![pair("index.ts", "removed.ts")]
+        );
+        assert_eq!(diff.added_cycles, vec![vec!["a.ts", "b.ts"]]);
+        assert!(diff.resolved_cycles.is_empty());
+        assert_eq!(diff.added_unused_exports, vec!["b.ts#unusedB"]);
+        assert_eq!(diff.resolved_unused_exports, vec!["a.ts#unusedA"]);
+        assert_eq!(diff.added_entry_exports, vec!["index.ts#added"]);
+        assert_eq!(diff.removed_entry_exports, vec!["index.ts#old"]);
+        assert_eq!(diff.get_change_count(), 8);
+
+        let reversed = compare_snapshots(&head, &base).unwrap();
+        assert_eq!(reversed.resolved_cycles, vec![vec!["a.ts", "b.ts"]]);
+    }
+
+    fn build_snapshot(paths: &[&str], dependencies: Vec<(usize, usize)>) -> LanternDump {
+        let modules = paths
+            .iter()
+            .map(|path| LanternDumpModule {
+                path: PathBuf::from(path),
+                is_entry: false,
+                symbols: Vec::new(),
+            })
+            .collect();
+        return LanternDump {
+            version: LanternDump::VERSION,
+            modules,
+            symbols: Vec::new(),
+            unresolved_imports: Vec::new(),
+            dependencies,
+        };
+    }
+
+    #[test]
+    fn cycles_ignore_module_order() {
+        // a → b → c → a and a → c → b → a, numbered differently on each side.
+        let base = build_snapshot(
+            &["a.ts", "b.ts", "c.ts"],
+            vec![(0, 1), (1, 2), (2, 0), (0, 2), (2, 1), (1, 0)],
+        );
+        let head = build_snapshot(
+            &["c.ts", "b.ts", "a.ts"],
+            vec![(2, 1), (1, 0), (0, 2), (2, 0), (0, 1), (1, 2)],
+        );
+        assert!(compare_snapshots(&base, &head).unwrap().is_empty());
+
+        let head = build_snapshot(
+            &["a.ts", "b.ts", "c.ts", "d.ts"],
+            vec![(0, 1), (1, 2), (2, 0), (0, 2), (2, 1), (1, 0), (3, 3)],
+        );
+        let diff = compare_snapshots(&base, &head).unwrap();
+        assert_eq!(diff.added_cycles, vec![vec!["d.ts"]]);
+        assert!(diff.resolved_cycles.is_empty());
+    }
+
+    #[test]
+    fn loads_snapshot_files() {
+        let base = get_fixture_snapshot("diff_base");
+        let path =
+            std::env::temp_dir().join(format!("lantern_diff_snapshot_{}.json", std::process::id()));
+        base.save(&path).unwrap();
+
+        let root = load_fixture!("diff_base").canonicalize().unwrap();
+        let loaded = get_snapshot(&[], &DiffSide::Snapshot(path.clone()), &root).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(compare_snapshots(&base, &loaded).unwrap().is_empty());
+
+        // A missing snapshot is an error, not a revision to analyse.
+        assert!(get_snapshot(&[], &DiffSide::Snapshot(path), &root).is_err());
+        assert!(get_snapshot(&[], &DiffSide::WorkingTree, &root).is_err());
+    }
+}
@@ -0,0 +1,54 @@
+use std::{fs::File, io::Write, path::PathBuf};
+
+use clap::ValueEnum;
+use color_eyre::eyre::Result;
+
+use lantern_dependency_graph::dump::{LanternDump, LanternDumpFormat};
+use lantern_dependency_graph::LanternFileDependencyMap;
+
+use crate::commands::source::SourceOptions;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum DumpFormat {
+    Json,
+    Binary,
+}
+
+pub struct DumpOptions {
+    /// Directory module paths are relative to, defaults to the current directory.
+    pub root: Option<PathBuf>,
+    /// File to write the dump to, defaults to stdout.
+    pub output: Option<PathBuf>,
+    pub format: DumpFormat,
+    pub source: SourceOptions,
+}
+
+pub fn run(entry_points: &[PathBuf], options: &DumpOptions) -> Result<()> {
+    let dump = get_dump(entry_points, options)?;
+    let format = match options.format {
+        DumpFormat::Json => LanternDumpFormat::Json,
+        DumpFormat::Binary => LanternDumpFormat::Binary,
+    };
+
+    match &options.output {
+        Some(output) => dump.write(&mut File::create(output)?, format)?,
+        None => {
+            let mut stdout = std::io::stdout().lock();
+            dump.write(&mut stdout, format)?;
+            stdout.flush()?;
+        }
+    }
+
+    return Ok(());
+}
+
+pub fn get_dump(entry_points: &[PathBuf], options: &DumpOptions) -> Result<LanternDump> {
+    let root = match &options.root {
+        Some(root) => root.canonicalize()?,
+        None => std::env::current_dir()?.canonicalize()?,
+    };
+    let ln_map = options.source.build_symbols_map(entry_points)?;
+    let mut depgraph = LanternFileDependencyMap::new(ln_map);
+    depgraph.build_dependency_graph();
+    return Ok(LanternDump::from_dependency_map(&mut depgraph, &root));
+}
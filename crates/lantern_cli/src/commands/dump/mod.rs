@@ -0,0 +1,4 @@
+pub mod command;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    use lantern_dependency_graph::dump::{LanternDump, LanternDumpFormat, LanternDumpSymbolKind};
+    use lantern_fs::OsFileSystem;
+    use lantern_testing::load_fixture;
+
+    use crate::commands::dump::command::{get_dump, DumpFormat, DumpOptions};
+    use crate::commands::source::SourceOptions;
+    use crate::commands::unused_exports::find_unused_exports::find_unused_exports;
+
+    fn get_fixture_dump() -> (PathBuf, LanternDump) {
+        let root = load_fixture!("dump_project").canonicalize().unwrap();
+        let options = DumpOptions {
+            root: Some(root.clone()),
+            output: None,
+            format: DumpFormat::Json,
+            source: SourceOptions::default(),
+        };
+        let dump = get_dump(&[root.join("index.ts")], &options).unwrap();
+        return (root, dump);
+    }
+
+    fn reload(dump: &LanternDump, format: LanternDumpFormat) -> LanternDump {
+        let mut bytes = Vec::new();
+        dump.write(&mut bytes, format).unwrap();
+        return LanternDump::from_bytes(&bytes).unwrap();
+    }
+
+    #[test]
+    fn dump_paths_and_positions() {
+        let (_, dump) = get_fixture_dump();
+        let paths = dump
+            .modules
+            .iter()
+            .map(|module| module.path.to_str().unwrap())
+            .collect::<Vec<&str>>();
+        assert_eq!(paths, vec!["index.ts", "a.ts", "b.ts"]);
+        assert!(dump.modules[0].is_entry);
+        assert_eq!(dump.dependencies, vec![(0, 1), (1, 2), (2, 1)]);
+
+        let unresolved = &dump.unresolved_imports[0];
+        assert_eq!(unresolved.source, "./missing");
+        assert_eq!(unresolved.error, "Cannot find module './missing'");
+        assert_eq!(unresolved.position.start_line, 2);
+        assert_eq!(unresolved.position.start_column, 19);
+
+        let unused = dump
+            .symbols
+            .iter()
+            .find(|symbol| symbol.line == 4 && symbol.module_id == 1)
+            .unwrap();
+        assert_eq!(unused.column, 14);
+        assert_eq!(unused.kind, LanternDumpSymbolKind::ExportDecl);
+        assert_eq!(unused.name.as_deref(), Some("unused"));
+        assert_eq!(unused.export_name.as_deref(), Some("unused"));
+        assert_eq!(unused.source_module, None);
+
+        let import = dump
+            .symbols
+            .iter()
+            .find(|symbol| {
+                symbol.module_id == 0 && symbol.kind == LanternDumpSymbolKind::ImportNamed
+            })
+            .unwrap();
+        assert_eq!(import.imported_name.as_deref(), Some("a"));
+        assert_eq!(import.source_module, Some(1));
+        assert!(!import.type_only);
+    }
+
+    #[test]
+    fn round_trip() {
+        let (root, dump) = get_fixture_dump();
+        let json = serde_json::to_value(&dump).unwrap();
+
+        for format in [LanternDumpFormat::Json, LanternDumpFormat::Binary] {
+            let loaded = reload(&dump, format);
+            assert_eq!(serde_json::to_value(&loaded).unwrap(), json);
+
+            let depgraph = loaded
+                .to_dependency_map(&root, Arc::new(OsFileSystem))
+                .unwrap();
+            assert_eq!(depgraph.symbols_map.modules.len(), 3);
+            assert_eq!(depgraph.symbols_map.modules[1].file_path, root.join("a.ts"));
+            assert!(depgraph.inverse_dependency_map[&1].contains(&0));
+            let unused = find_unused_exports(&depgraph.symbols_map).unwrap();
+            assert_eq!(unused.len(), 1);
+            assert_eq!(unused[0].get_name(), Some("unused"));
+        }
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        let (_, mut dump) = get_fixture_dump();
+        dump.version = LanternDump::VERSION + 1;
+
+        for format in [LanternDumpFormat::Json, LanternDumpFormat::Binary] {
+            let mut bytes = Vec::new();
+            dump.write(&mut bytes, format).unwrap();
+            assert!(LanternDump::from_bytes(&bytes).is_err());
+        }
+    }
+
+    #[test]
+    fn rejects_out_of_range_module_ids() {
+        let (_, mut dump) = get_fixture_dump();
+        let module_count = dump.modules.len();
+        dump.symbols[0].module_id = module_count;
+        let mut dependency_dump = get_fixture_dump().1;
+        dependency_dump.dependencies.push((0, module_count));
+
+        for dump in [dump, dependency_dump] {
+            for format in [LanternDumpFormat::Json, LanternDumpFormat::Binary] {
+                let mut bytes = Vec::new();
+                dump.write(&mut bytes, format).unwrap();
+                assert!(LanternDump::from_bytes(&bytes).is_err());
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use std::path::Path;
+
+use lantern_code_annotation::CodeAnnotation;
+use lantern_dependency_graph::LanternFileDependencyMap;
+use lantern_symbols_map::symbol::LNSymbolData;
+
+pub struct EdgeAnnotation {
+    /// One line description of the edge, e.g. "a.ts:3 imports b.ts".
+    pub summary: String,
+    pub annotation: CodeAnnotation,
+}
+
+/// Annotates the statement in `from` that makes it depend on `to`.
+pub fn annotate_edge(
+    depgraph: &mut LanternFileDependencyMap,
+    from: usize,
+    to: usize,
+) -> Option<EdgeAnnotation> {
+    return annotate_edge_with_note(depgraph, from, to, None);
+}
+
+/// Same as `annotate_edge`, with a note explaining why the edge is reported.
+pub fn annotate_edge_with_note(
+    depgraph: &mut LanternFileDependencyMap,
+    from: usize,
+    to: usize,
+    note: Option<&str>,
+) -> Option<EdgeAnnotation> {
+    let symbol = depgraph
+        .get_dependency_symbols(from, to)
+        .into_iter()
+        .next()?
+        .clone();
+
+    let verb = match symbol.symbol {
+        LNSymbolData::ExportAll(_) | LNSymbolData::ExportNamed(_, _, _, _) => "re-exports",
+        _ => "imports",
+    };
+
+    let ln_map = &mut depgraph.symbols_map;
+    let span = symbol.get_span();
+    let line = ln_map.get_line_number_from_span(from, span);
+    let from_path = display_path(ln_map.get_module_path(from));
+    let to_path = display_path(ln_map.get_module_path(to));
+
+    let mut annotation = CodeAnnotation::new(
+        ln_map.get_module_path(from).clone(),
+        ln_map.get_module_source(from).to_string(),
+    );
+    let mut summary = format!("{}:{} {} {}", from_path, line, verb, to_path);
+    let mut message = format!("{} {}", verb, to_path);
+    if let Some(note) = note {
+        summary = format!("{} ({})", summary, note);
+        message = format!("{}: {}", message, note);
+    }
+    annotation.annotate(message, line, *span);
+
+    return Some(EdgeAnnotation {
+        summary,
+        annotation,
+    });
+}
+
+/// Formats a path relative to the current directory when possible.
+pub fn display_path(path: &Path) -> String {
+    if let Ok(cwd) = std::env::current_dir() {
+        if let Ok(relative) = path.strip_prefix(cwd) {
+            return relative.display().to_string();
+        }
+    }
+    return path.display().to_string();
+}
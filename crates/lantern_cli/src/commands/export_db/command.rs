@@ -0,0 +1,295 @@
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+};
+
+use color_eyre::eyre::{eyre, Result};
+use rusqlite::{params, Connection, Transaction};
+
+use lantern_dependency_graph::graph::{get_edge_kind, LanternEdgeKind};
+use lantern_dependency_graph::LanternFileDependencyMap;
+use lantern_formatters::findings_reporter::LanternFinding;
+use lantern_symbols_map::symbol::{LNSymbol, LNSymbolData};
+
+use crate::commands::baseline::relative_path;
+use crate::commands::cycles::find_cycles::find_cyclic_components;
+use crate::commands::files_with_reexports::find_files_with_reexports;
+use crate::commands::findings::{
+    cyclic_component_finding, re_export_finding, unresolved_import_finding, unused_export_finding,
+};
+use crate::commands::source::SourceOptions;
+use crate::commands::unused_exports::find_unused_exports::find_unused_exports;
+
+const SCHEMA: &str = "
+CREATE TABLE modules (
+    id INTEGER PRIMARY KEY,
+    path TEXT NOT NULL,
+    is_entry INTEGER NOT NULL
+);
+CREATE TABLE symbols (
+    id INTEGER PRIMARY KEY,
+    module_id INTEGER NOT NULL REFERENCES modules(id),
+    kind TEXT NOT NULL,
+    name TEXT,
+    export_name TEXT,
+    source_module_id INTEGER REFERENCES modules(id),
+    type_only INTEGER NOT NULL,
+    span_start INTEGER NOT NULL,
+    span_end INTEGER NOT NULL,
+    line INTEGER NOT NULL,
+    column INTEGER NOT NULL
+);
+CREATE TABLE edges (
+    from_module_id INTEGER NOT NULL REFERENCES modules(id),
+    to_module_id INTEGER NOT NULL REFERENCES modules(id),
+    kind TEXT NOT NULL,
+    PRIMARY KEY (from_module_id, to_module_id)
+);
+CREATE TABLE unresolved_imports (
+    module_id INTEGER NOT NULL REFERENCES modules(id),
+    source TEXT NOT NULL,
+    error TEXT NOT NULL,
+    span_start INTEGER NOT NULL,
+    span_end INTEGER NOT NULL,
+    line INTEGER NOT NULL,
+    column INTEGER NOT NULL
+);
+CREATE TABLE findings (
+    rule TEXT NOT NULL,
+    severity TEXT NOT NULL,
+    message TEXT NOT NULL,
+    path TEXT NOT NULL,
+    start_line INTEGER NOT NULL,
+    start_column INTEGER NOT NULL,
+    end_line INTEGER NOT NULL,
+    end_column INTEGER NOT NULL,
+    fingerprint TEXT NOT NULL
+);
+CREATE INDEX symbols_module_id ON symbols (module_id);
+CREATE INDEX edges_to_module_id ON edges (to_module_id);
+";
+
+pub struct ExportDbOptions {
+    /// Directory module and finding paths are relative to, defaults to the current directory.
+    pub root: Option<PathBuf>,
+    /// Revision to read files from.
+    pub source: SourceOptions,
+}
+
+/// Writes the analysis to a new SQLite database at `output`. The database is built next to
+/// it and only replaces an existing file once complete.
+pub fn run(output: &Path, entry_points: &[PathBuf], options: &ExportDbOptions) -> Result<()> {
+    let root = match &options.root {
+        Some(root) => root.canonicalize()?,
+        None => std::env::current_dir()?.canonicalize()?,
+    };
+    let ln_map = options.source.build_symbols_map(entry_points)?;
+    let mut depgraph = LanternFileDependencyMap::new(ln_map);
+    depgraph.build_dependency_graph();
+
+    let file_name = output
+        .file_name()
+        .ok_or_else(|| eyre!("{:?} is not a file path", output))?;
+    let mut temp_name = OsString::from(".");
+    temp_name.push(file_name);
+    temp_name.push(format!(".{}.tmp", std::process::id()));
+    let temp_path = output.with_file_name(temp_name);
+
+    let result = write_database(&temp_path, &mut depgraph, &root)
+        .and_then(|_| Ok(std::fs::rename(&temp_path, output)?));
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp_path);
+    }
+    result?;
+
+    println!(
+        "Exported {} modules and {} symbols to {}",
+        depgraph.symbols_map.modules.len(),
+        depgraph.symbols_map.symbols.len(),
+        output.display()
+    );
+    return Ok(());
+}
+
+fn write_database(path: &Path, depgraph: &mut LanternFileDependencyMap, root: &Path) -> Result<()> {
+    let mut connection = Connection::open(path)?;
+    export(&mut connection, depgraph, root)?;
+    connection.close().map_err(|(_, err)| err)?;
+    return Ok(());
+}
+
+pub fn export(
+    connection: &mut Connection,
+    depgraph: &mut LanternFileDependencyMap,
+    root: &Path,
+) -> Result<()> {
+    let transaction = connection.transaction()?;
+    transaction.execute_batch(SCHEMA)?;
+    insert_modules(&transaction, depgraph, root)?;
+    insert_symbols(&transaction, depgraph)?;
+    insert_edges(&transaction, depgraph)?;
+    insert_unresolved_imports(&transaction, depgraph, root)?;
+    insert_findings(&transaction, depgraph, root)?;
+    transaction.commit()?;
+    return Ok(());
+}
+
+fn insert_modules(
+    transaction: &Transaction,
+    depgraph: &LanternFileDependencyMap,
+    root: &Path,
+) -> Result<()> {
+    let ln_map = &depgraph.symbols_map;
+    let mut statement =
+        transaction.prepare("INSERT INTO modules (id, path, is_entry) VALUES (?1, ?2, ?3)")?;
+    for (module_id, module) in ln_map.modules.iter().enumerate() {
+        statement.execute(params![
+            module_id,
+            relative_path(ln_map, module_id, root),
+            module.is_entry
+        ])?;
+    }
+    return Ok(());
+}
+
+fn insert_symbols(
+    transaction: &Transaction,
+    depgraph: &mut LanternFileDependencyMap,
+) -> Result<()> {
+    let ln_map = &mut depgraph.symbols_map;
+    let mut statement = transaction.prepare(
+        "INSERT INTO symbols (id, module_id, kind, name, export_name, source_module_id, \
+         type_only, span_start, span_end, line, column) \
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+    )?;
+    for symbol_id in 0..ln_map.symbols.len() {
+        let symbol = ln_map.symbols[symbol_id].clone();
+        let span = symbol.get_span();
+        let (line, column) = ln_map.get_position_from_offset(symbol.module_id, span.start);
+        statement.execute(params![
+            symbol_id,
+            symbol.module_id,
+            get_symbol_kind(&symbol),
+            symbol.get_name(),
+            symbol.get_export_name(),
+            symbol
+                .get_file_reference()
+                .map(|file_ref| file_ref.module_id),
+            symbol.is_type_only(),
+            span.start,
+            span.end,
+            line,
+            column
+        ])?;
+    }
+    return Ok(());
+}
+
+fn insert_edges(transaction: &Transaction, depgraph: &LanternFileDependencyMap) -> Result<()> {
+    let mut statement = transaction
+        .prepare("INSERT INTO edges (from_module_id, to_module_id, kind) VALUES (?1, ?2, ?3)")?;
+    for (from, dependencies) in &depgraph.dependency_map {
+        for to in dependencies {
+            let kind = match get_edge_kind(depgraph, *from, *to) {
+                LanternEdgeKind::Import => "import",
+                LanternEdgeKind::TypeImport => "type-import",
+                LanternEdgeKind::ReExport => "re-export",
+            };
+            statement.execute(params![from, to, kind])?;
+        }
+    }
+    return Ok(());
+}
+
+fn insert_unresolved_imports(
+    transaction: &Transaction,
+    depgraph: &mut LanternFileDependencyMap,
+    root: &Path,
+) -> Result<()> {
+    let ln_map = &mut depgraph.symbols_map;
+    let mut statement = transaction.prepare(
+        "INSERT INTO unresolved_imports (module_id, source, error, span_start, span_end, line, \
+         column) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+    )?;
+    for unresolved_import in ln_map.unresolved_imports.clone() {
+        let span = unresolved_import.span;
+        let (line, column) =
+            ln_map.get_position_from_offset(unresolved_import.module_id, span.start);
+        statement.execute(params![
+            unresolved_import.module_id,
+            unresolved_import.source,
+            unresolved_import.get_relative_error(root),
+            span.start,
+            span.end,
+            line,
+            column
+        ])?;
+    }
+    return Ok(());
+}
+
+/// Findings of every rule that doesn't need configuration.
+fn insert_findings(
+    transaction: &Transaction,
+    depgraph: &mut LanternFileDependencyMap,
+    root: &Path,
+) -> Result<()> {
+    let mut findings: Vec<LanternFinding> = Vec::new();
+    let ln_map = &mut depgraph.symbols_map;
+    for symbol in find_unused_exports(ln_map)? {
+        findings.push(unused_export_finding(ln_map, &symbol, root));
+    }
+    for symbol in find_files_with_reexports(ln_map)? {
+        findings.push(re_export_finding(ln_map, &symbol, root));
+    }
+    for unresolved_import in ln_map.unresolved_imports.clone() {
+        findings.push(unresolved_import_finding(ln_map, &unresolved_import, root));
+    }
+    for component in find_cyclic_components(depgraph) {
+        findings.extend(cyclic_component_finding(depgraph, &component, root));
+    }
+
+    let mut statement = transaction.prepare(
+        "INSERT INTO findings (rule, severity, message, path, start_line, start_column, \
+         end_line, end_column, fingerprint) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+    )?;
+    for finding in findings {
+        let location = &finding.location;
+        statement.execute(params![
+            finding.rule.id,
+            finding.rule.severity.as_str(),
+            finding.message,
+            location.path.display().to_string(),
+            location.start_line,
+            location.start_column,
+            location.end_line,
+            location.end_column,
+            finding.fingerprint
+        ])?;
+    }
+    return Ok(());
+}
+
+fn get_symbol_kind(symbol: &LNSymbol) -> &'static str {
+    return match &symbol.symbol {
+        LNSymbolData::ExportAll(_) => "export-all",
+        LNSymbolData::ExportNamed(_, _, _, Some(_)) => "re-export",
+        LNSymbolData::ExportNamed(_, _, _, None) => "export-named",
+        LNSymbolData::ExportDecl(_, _) => "export-decl",
+        LNSymbolData::ExportFnDecl(_, _) => "export-fn",
+        LNSymbolData::ExportClassDecl(_, _) => "export-class",
+        LNSymbolData::ExportEnumDecl(_, _) => "export-enum",
+        LNSymbolData::ExportInterfaceDecl(_, _) => "export-interface",
+        LNSymbolData::ExportTypeAliasDecl(_, _) => "export-type-alias",
+        LNSymbolData::ExportDefaultExpr(_)
+        | LNSymbolData::ExportDefaultClassDecl(_, _)
+        | LNSymbolData::ExportDefaultFnDecl(_, _)
+        | LNSymbolData::ExportDefaultInterfaceDecl(_, _)
+        | LNSymbolData::ExportDefaultIdentifier(_, _)
+        | LNSymbolData::ExportDefaultCallExpression(_, _)
+        | LNSymbolData::ExportDefaultConditionalExpression(_, _, _) => "export-default",
+        LNSymbolData::ImportDefault(_, _, _, _) => "import-default",
+        LNSymbolData::ImportStar(_, _, _, _) => "import-star",
+        LNSymbolData::ImportNamed(_, _, _, _, _) => "import-named",
+    };
+}
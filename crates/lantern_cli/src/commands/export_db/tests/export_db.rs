@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use rusqlite::Connection;
+
+    use crate::commands::export_db::command::{export, run, ExportDbOptions};
+    use crate::commands::source::SourceOptions;
+    use crate::commands::test_utils::{build_depgraph, fixture_root};
+
+    fn export_fixture() -> Connection {
+        let root = fixture_root("export_db_project");
+        let mut depgraph = build_depgraph("export_db_project", &["index.ts"]);
+
+        let mut connection = Connection::open_in_memory().unwrap();
+        export(&mut connection, &mut depgraph, &root).unwrap();
+        return connection;
+    }
+
+    fn query(connection: &Connection, sql: &str) -> Vec<String> {
+        let mut statement = connection.prepare(sql).unwrap();
+        return statement
+            .query_map([], |row| row.get::<_, String>(0))
+            .unwrap()
+            .map(|row| row.unwrap())
+            .collect();
+    }
+
+    #[test]
+    fn exports_modules_and_edges() {
+        let connection = export_fixture();
+        assert_eq!(
+            query(&connection, "SELECT path FROM modules ORDER BY id"),
+            vec!["index.ts", "a.ts", "types.ts", "b.ts"]
+        );
+        assert_eq!(
+            query(
+                &connection,
+                "SELECT f.path || ' ' || e.kind || ' ' || t.path FROM edges e \
+                 JOIN modules f ON f.id = e.from_module_id \
+                 JOIN modules t ON t.id = e.to_module_id ORDER BY 1"
+            ),
+            vec![
+                "a.ts import b.ts",
+                "b.ts import a.ts",
+                "index.ts import a.ts",
+                "index.ts type-import types.ts"
+            ]
+        );
+    }
+
+    #[test]
+    fn exports_symbols() {
+        let connection = export_fixture();
+        assert_eq!(
+            query(
+                &connection,
+                "SELECT kind || ' ' || name || ' ' || type_only || ' ' || line || ':' || column \
+                 FROM symbols WHERE module_id = 0 ORDER BY id"
+            ),
+            vec!["import-named a 0 1:10", "import-named Options 1 3:15"]
+        );
+        assert_eq!(
+            query(
+                &connection,
+                "SELECT source || ' ' || line || ':' || column FROM unresolved_imports"
+            ),
+            vec!["./missing 2:19"]
+        );
+    }
+
+    #[test]
+    fn exports_findings() {
+        let connection = export_fixture();
+        assert_eq!(
+            query(
+                &connection,
+                "SELECT rule || ' ' || severity || ' ' || fingerprint FROM findings ORDER BY 1"
+            ),
+            vec![
+                "import-cycle warning a.ts, b.ts",
+                "unresolved-import error index.ts#./missing",
+                "unused-export warning a.ts#unused"
+            ]
+        );
+    }
+
+    #[test]
+    fn replaces_output_when_complete() {
+        let root = fixture_root("export_db_project");
+        let dir = std::env::temp_dir().join(format!("lantern_export_db_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let output = dir.join("out.sqlite");
+        std::fs::write(&output, "not a database").unwrap();
+
+        let options = ExportDbOptions {
+            root: Some(root.clone()),
+            source: SourceOptions::default(),
+        };
+        run(&output, &[root.join("index.ts")], &options).unwrap();
+
+        let connection = Connection::open(&output).unwrap();
+        assert_eq!(
+            query(&connection, "SELECT path FROM modules WHERE id = 0"),
+            vec!["index.ts"]
+        );
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
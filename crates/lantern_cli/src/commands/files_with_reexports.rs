@@ -3,15 +3,41 @@ use std::{collections::HashMap, path::PathBuf};
 use color_eyre::eyre::Result;
 
 use lantern_code_annotation::CodeAnnotation;
+use lantern_formatters::findings_reporter::{LanternFinding, LanternSeverity};
 
 use lantern_symbols_map::symbol::LNSymbol;
 use lantern_symbols_map::symbol::LNSymbolData;
 use lantern_symbols_map::symbols_map::LNSymbolsMap;
 
-pub fn run(entry_points: &Vec<PathBuf>) -> Result<()> {
-    let mut ln_map = lantern_symbols_map::build_symbols_map(&entry_points)?;
+use crate::commands::findings::{get_reporter, re_export_finding, ReportFormat, RE_EXPORT_RULE};
+use crate::commands::source::SourceOptions;
+
+pub struct FilesWithReExportsOptions {
+    pub format: ReportFormat,
+    /// Revision to read files from.
+    pub source: SourceOptions,
+}
+
+/// Returns severities of the reported re-exports.
+pub fn run(
+    entry_points: &[PathBuf],
+    options: &FilesWithReExportsOptions,
+) -> Result<Vec<LanternSeverity>> {
+    let mut ln_map = options.source.build_symbols_map(entry_points)?;
     let mut annotations: HashMap<usize, CodeAnnotation> = HashMap::new();
     let re_exports = find_files_with_reexports(&ln_map)?;
+    let severities = vec![RE_EXPORT_RULE.severity; re_exports.len()];
+
+    let root = std::env::current_dir()?.canonicalize()?;
+    if let Some(reporter) = get_reporter(options.format, &root) {
+        let findings = re_exports
+            .iter()
+            .map(|symbol| re_export_finding(&mut ln_map, symbol, &root))
+            .collect::<Vec<LanternFinding>>();
+        println!("{}", reporter.report(&findings));
+        return Ok(severities);
+    }
+
     let total = re_exports.len();
 
     for symbol in re_exports {
@@ -42,10 +68,10 @@ pub fn run(entry_points: &Vec<PathBuf>) -> Result<()> {
 
     println!("Total re-exports found: {}", total);
 
-    return Ok(());
+    return Ok(severities);
 }
 
-fn find_files_with_reexports(ln_map: &LNSymbolsMap) -> Result<Vec<LNSymbol>> {
+pub fn find_files_with_reexports(ln_map: &LNSymbolsMap) -> Result<Vec<LNSymbol>> {
     let mut re_exports: Vec<LNSymbol> = Vec::new();
 
     for module in &ln_map.modules {
@@ -0,0 +1,296 @@
+use std::path::Path;
+
+use clap::{Args, ValueEnum};
+
+use lantern_dependency_graph::LanternFileDependencyMap;
+use lantern_formatters::findings_reporter::{
+    LanternFinding, LanternFindingsReporter, LanternLocation, LanternRule, LanternSeverity,
+};
+use lantern_formatters::github::GitHubReporter;
+use lantern_formatters::gitlab::GitLabReporter;
+use lantern_formatters::junit::JUnitReporter;
+use lantern_formatters::sarif::SarifReporter;
+use lantern_symbols_map::symbol::LNSymbol;
+use lantern_symbols_map::symbols_map::{LNSymbolsMap, LNUnresolvedImport};
+
+use crate::commands::baseline::{cycle_key, export_key, relative_path};
+
+pub const UNUSED_EXPORT_RULE: LanternRule = LanternRule {
+    id: "unused-export",
+    description: "Export isn't imported by any module reachable from the entries",
+    severity: LanternSeverity::Warning,
+};
+
+pub const RE_EXPORT_RULE: LanternRule = LanternRule {
+    id: "re-export",
+    description: "Module re-exports symbols of another module",
+    severity: LanternSeverity::Note,
+};
+
+pub const CYCLE_RULE: LanternRule = LanternRule {
+    id: "import-cycle",
+    description: "Modules depend on each other in a cycle",
+    severity: LanternSeverity::Warning,
+};
+
+pub const UNRESOLVED_IMPORT_RULE: LanternRule = LanternRule {
+    id: "unresolved-import",
+    description: "Import source can't be resolved to a file",
+    severity: LanternSeverity::Error,
+};
+
+pub const BOUNDARY_VIOLATION_RULE: LanternRule = LanternRule {
+    id: "boundary-violation",
+    description: "Import isn't allowed by the boundary rules",
+    severity: LanternSeverity::Error,
+};
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ReportFormat {
+    /// Annotated source code
+    Text,
+    /// SARIF 2.1.0 log for code scanning tools
+    Sarif,
+    /// GitHub Actions workflow commands annotating the files
+    #[value(name = "github")]
+    GitHub,
+    /// GitLab Code Quality report
+    #[value(name = "gitlab")]
+    GitLab,
+    /// JUnit XML with a failed test case per finding
+    #[value(name = "junit")]
+    JUnit,
+}
+
+/// Reporter for machine readable formats, commands print the text format themselves.
+pub fn get_reporter(format: ReportFormat, root: &Path) -> Option<Box<dyn LanternFindingsReporter>> {
+    return match format {
+        ReportFormat::Text => None,
+        ReportFormat::Sarif => Some(Box::new(SarifReporter::new(root))),
+        ReportFormat::GitHub => Some(Box::new(GitHubReporter)),
+        ReportFormat::GitLab => Some(Box::new(GitLabReporter)),
+        ReportFormat::JUnit => Some(Box::new(JUnitReporter)),
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FailOn {
+    /// More than this many findings.
+    Count(usize),
+    /// Any finding of this severity or above.
+    Severity(LanternSeverity),
+}
+
+pub fn parse_fail_on(value: &str) -> Result<FailOn, String> {
+    if let Ok(count) = value.parse::<usize>() {
+        return Ok(FailOn::Count(count));
+    }
+    return match value {
+        "note" => Ok(FailOn::Severity(LanternSeverity::Note)),
+        "warning" => Ok(FailOn::Severity(LanternSeverity::Warning)),
+        "error" => Ok(FailOn::Severity(LanternSeverity::Error)),
+        _ => Err("expected a number of findings or one of note, warning, error".to_string()),
+    };
+}
+
+/// When reported findings should fail the run.
+#[derive(Debug, Clone, Args)]
+pub struct FailOptions {
+    /// Fail when there are more than this many findings or any finding of this severity or
+    /// above (note, warning, error), defaults to any finding
+    #[arg(long, value_parser = parse_fail_on)]
+    pub fail_on: Option<FailOn>,
+
+    /// Fail when there are more than this many warnings
+    #[arg(long)]
+    pub max_warnings: Option<usize>,
+}
+
+impl FailOptions {
+    pub fn should_fail(&self, severities: &[LanternSeverity]) -> bool {
+        // With only --max-warnings, warnings are judged by the limit and errors still fail.
+        let fail_on = self.fail_on.unwrap_or(if self.max_warnings.is_some() {
+            FailOn::Severity(LanternSeverity::Error)
+        } else {
+            FailOn::Count(0)
+        });
+        // Counts are limits like --max-warnings, reaching them is still fine.
+        let failed = match fail_on {
+            FailOn::Count(count) => severities.len() > count,
+            FailOn::Severity(min) => severities.iter().any(|severity| *severity >= min),
+        };
+        let warnings = severities
+            .iter()
+            .filter(|severity| **severity == LanternSeverity::Warning)
+            .count();
+        return failed || self.max_warnings.is_some_and(|max| warnings > max);
+    }
+}
+
+pub fn unused_export_finding(
+    ln_map: &mut LNSymbolsMap,
+    symbol: &LNSymbol,
+    root: &Path,
+) -> LanternFinding {
+    let message = match symbol.get_name() {
+        Some(name) => format!("unused export: {}", name),
+        None => "unused export".to_owned(),
+    };
+    return LanternFinding {
+        rule: UNUSED_EXPORT_RULE,
+        message,
+        location: get_location(ln_map, symbol, root, None),
+        related_locations: Vec::new(),
+        fingerprint: export_key(ln_map, symbol, root),
+    };
+}
+
+pub fn re_export_finding(
+    ln_map: &mut LNSymbolsMap,
+    symbol: &LNSymbol,
+    root: &Path,
+) -> LanternFinding {
+    let message = match symbol.get_name() {
+        Some(name) => format!("re-export: {}", name),
+        None => "re-export".to_owned(),
+    };
+    return LanternFinding {
+        rule: RE_EXPORT_RULE,
+        message,
+        location: get_location(ln_map, symbol, root, None),
+        related_locations: Vec::new(),
+        fingerprint: export_key(ln_map, symbol, root),
+    };
+}
+
+pub fn unresolved_import_finding(
+    ln_map: &mut LNSymbolsMap,
+    unresolved_import: &LNUnresolvedImport,
+    root: &Path,
+) -> LanternFinding {
+    let module_id = unresolved_import.module_id;
+    let span = unresolved_import.span;
+    let (start_line, start_column) = ln_map.get_position_from_offset(module_id, span.start);
+    let (end_line, end_column) = ln_map.get_position_from_offset(module_id, span.end);
+    let path = relative_path(ln_map, module_id, root);
+    return LanternFinding {
+        rule: UNRESOLVED_IMPORT_RULE,
+        message: format!("unresolved import: {}", unresolved_import.source),
+        location: LanternLocation {
+            path: path.clone().into(),
+            start_line,
+            start_column,
+            end_line,
+            end_column,
+            message: None,
+        },
+        related_locations: Vec::new(),
+        fingerprint: format!("{}#{}", path, unresolved_import.source),
+    };
+}
+
+/// Reported at the first import of the cycle, with every import as a related location.
+pub fn cycle_finding(
+    depgraph: &mut LanternFileDependencyMap,
+    cycle: &[usize],
+    root: &Path,
+) -> Option<LanternFinding> {
+    let mut locations = Vec::new();
+    for (pos, from) in cycle.iter().enumerate() {
+        let to = cycle[(pos + 1) % cycle.len()];
+        let symbol = depgraph
+            .get_dependency_symbols(*from, to)
+            .into_iter()
+            .next()?
+            .clone();
+        let message = format!("imports {}", relative_path(&depgraph.symbols_map, to, root));
+        locations.push(get_location(
+            &mut depgraph.symbols_map,
+            &symbol,
+            root,
+            Some(message),
+        ));
+    }
+
+    let mut hops = cycle
+        .iter()
+        .map(|module_id| relative_path(&depgraph.symbols_map, *module_id, root))
+        .collect::<Vec<String>>();
+    hops.push(hops[0].clone());
+
+    return Some(LanternFinding {
+        rule: CYCLE_RULE,
+        message: format!("import cycle: {}", hops.join(" → ")),
+        location: locations[0].clone(),
+        related_locations: locations,
+        fingerprint: cycle_key(depgraph, cycle, root).join(", "),
+    });
+}
+
+/// One finding per group of files importing each other, at the first import between
+/// them, with every import inside the group as a related location. Stable no matter the
+/// order modules were found in.
+pub fn cyclic_component_finding(
+    depgraph: &mut LanternFileDependencyMap,
+    component: &[usize],
+    root: &Path,
+) -> Option<LanternFinding> {
+    let key = cycle_key(depgraph, component, root);
+    let mut members = component.to_vec();
+    members.sort_by_key(|module_id| relative_path(&depgraph.symbols_map, *module_id, root));
+
+    let mut locations = Vec::new();
+    for from in &members {
+        for to in &members {
+            let is_dependency = depgraph
+                .dependency_map
+                .get(from)
+                .is_some_and(|dependencies| dependencies.contains(to));
+            if !is_dependency {
+                continue;
+            }
+            let symbol = depgraph
+                .get_dependency_symbols(*from, *to)
+                .into_iter()
+                .next()?
+                .clone();
+            let message = format!(
+                "imports {}",
+                relative_path(&depgraph.symbols_map, *to, root)
+            );
+            locations.push(get_location(
+                &mut depgraph.symbols_map,
+                &symbol,
+                root,
+                Some(message),
+            ));
+        }
+    }
+
+    return Some(LanternFinding {
+        rule: CYCLE_RULE,
+        message: format!("import cycle between {}", key.join(", ")),
+        location: locations.first()?.clone(),
+        related_locations: locations,
+        fingerprint: key.join(", "),
+    });
+}
+
+fn get_location(
+    ln_map: &mut LNSymbolsMap,
+    symbol: &LNSymbol,
+    root: &Path,
+    message: Option<String>,
+) -> LanternLocation {
+    let span = symbol.get_span();
+    let (start_line, start_column) = ln_map.get_position_from_offset(symbol.module_id, span.start);
+    let (end_line, end_column) = ln_map.get_position_from_offset(symbol.module_id, span.end);
+    return LanternLocation {
+        path: relative_path(ln_map, symbol.module_id, root).into(),
+        start_line,
+        start_column,
+        end_line,
+        end_column,
+        message,
+    };
+}
@@ -0,0 +1,21 @@
+use clap::ValueEnum;
+
+use lantern_dependency_graph::graph::LanternGraphGrouping;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum GroupBy {
+    /// Collapse files into their directories
+    Dir,
+    /// Collapse files into the packages they belong to
+    Package,
+}
+
+pub fn get_grouping(
+    group_by: Option<GroupBy>,
+    depth: Option<usize>,
+) -> Option<LanternGraphGrouping> {
+    return match group_by? {
+        GroupBy::Dir => Some(LanternGraphGrouping::Dir(depth)),
+        GroupBy::Package => Some(LanternGraphGrouping::Package),
+    };
+}
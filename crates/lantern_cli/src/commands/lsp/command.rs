@@ -0,0 +1,186 @@
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use color_eyre::eyre::Result;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+    Notification as LspNotification, PublishDiagnostics,
+};
+use lsp_types::request::{CodeLensRequest, Request as LspRequest};
+use lsp_types::{
+    CodeLensOptions, CodeLensParams, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
+    DidOpenTextDocumentParams, PublishDiagnosticsParams, ServerCapabilities,
+    TextDocumentIdentifier, TextDocumentSyncCapability, TextDocumentSyncKind, Url,
+};
+
+use crate::commands::lsp::workspace::Workspace;
+
+/// Custom request listing the import statements pointing at a document.
+pub const FIND_IMPORTERS_REQUEST: &str = "lantern/findImporters";
+
+/// Diagnostics are published once documents stop changing for this long.
+const PUBLISH_DELAY: Duration = Duration::from_millis(200);
+
+pub fn run(entry_points: &[PathBuf]) -> Result<()> {
+    let (connection, io_threads) = Connection::stdio();
+    serve(&connection, entry_points)?;
+    io_threads.join()?;
+    return Ok(());
+}
+
+/// Handles messages on `connection` until the client shuts the server down.
+pub fn serve(connection: &Connection, entry_points: &[PathBuf]) -> Result<()> {
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        code_lens_provider: Some(CodeLensOptions {
+            resolve_provider: Some(false),
+        }),
+        ..Default::default()
+    };
+    connection.initialize(serde_json::to_value(capabilities)?)?;
+
+    let mut workspace = Workspace::new(entry_points)?;
+    let mut published = HashSet::new();
+    publish_diagnostics(connection, &mut workspace, &mut published)?;
+
+    let mut is_pending = false;
+    loop {
+        let message = if is_pending {
+            match connection.receiver.recv_timeout(PUBLISH_DELAY) {
+                Ok(message) => message,
+                Err(err) if err.is_timeout() => {
+                    publish_diagnostics(connection, &mut workspace, &mut published)?;
+                    is_pending = false;
+                    continue;
+                }
+                Err(_) => return Ok(()),
+            }
+        } else {
+            match connection.receiver.recv() {
+                Ok(message) => message,
+                Err(_) => return Ok(()),
+            }
+        };
+
+        match message {
+            Message::Request(request) => {
+                if connection.handle_shutdown(&request)? {
+                    return Ok(());
+                }
+                let response = handle_request(&mut workspace, request);
+                connection.sender.send(Message::Response(response))?;
+            }
+            Message::Notification(notification) => {
+                if handle_notification(&mut workspace, notification)? {
+                    is_pending = true;
+                }
+            }
+            Message::Response(_) => {}
+        }
+    }
+}
+
+fn handle_request(workspace: &mut Workspace, request: Request) -> Response {
+    let result = match request.method.as_str() {
+        CodeLensRequest::METHOD => {
+            serde_json::from_value::<CodeLensParams>(request.params).map(|params| {
+                let path = to_path(&params.text_document.uri);
+                return serde_json::json!(workspace.get_code_lenses(&path));
+            })
+        }
+        FIND_IMPORTERS_REQUEST => serde_json::from_value::<TextDocumentIdentifier>(request.params)
+            .map(|params| {
+                let path = to_path(&params.uri);
+                return serde_json::json!(workspace.find_importers(&path));
+            }),
+        _ => {
+            return Response::new_err(
+                request.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("Unknown request {}", request.method),
+            );
+        }
+    };
+
+    return match result {
+        Ok(result) => Response::new_ok(request.id, result),
+        Err(err) => Response::new_err(request.id, ErrorCode::InvalidParams as i32, err.to_string()),
+    };
+}
+
+/// Returns whether the analysis changed.
+fn handle_notification(workspace: &mut Workspace, notification: Notification) -> Result<bool> {
+    match notification.method.as_str() {
+        DidOpenTextDocument::METHOD => {
+            let params: DidOpenTextDocumentParams = serde_json::from_value(notification.params)?;
+            let path = to_path(&params.text_document.uri);
+            workspace.set_document(path, params.text_document.text);
+        }
+        DidChangeTextDocument::METHOD => {
+            let params: DidChangeTextDocumentParams = serde_json::from_value(notification.params)?;
+            // Full sync, the last change has the whole document.
+            let text = match params.content_changes.into_iter().last() {
+                Some(change) => change.text,
+                None => return Ok(false),
+            };
+            workspace.set_document(to_path(&params.text_document.uri), text);
+        }
+        DidCloseTextDocument::METHOD => {
+            let params: DidCloseTextDocumentParams = serde_json::from_value(notification.params)?;
+            workspace.close_document(&to_path(&params.text_document.uri));
+        }
+        _ => return Ok(false),
+    }
+    return Ok(true);
+}
+
+/// Sends diagnostics for every file with problems and clears them for files that no
+/// longer have any.
+fn publish_diagnostics(
+    connection: &Connection,
+    workspace: &mut Workspace,
+    published: &mut HashSet<PathBuf>,
+) -> Result<()> {
+    let diagnostics = workspace.get_diagnostics();
+    let mut cleared = published
+        .iter()
+        .filter(|path| !diagnostics.contains_key(*path))
+        .cloned()
+        .collect::<Vec<PathBuf>>();
+    cleared.sort();
+
+    let send = |path: &Path, diagnostics: Vec<lsp_types::Diagnostic>| -> Result<()> {
+        let uri = match Url::from_file_path(path) {
+            Ok(uri) => uri,
+            Err(_) => return Ok(()),
+        };
+        let params = PublishDiagnosticsParams::new(uri, diagnostics, None);
+        let notification = Notification::new(PublishDiagnostics::METHOD.to_string(), params);
+        connection
+            .sender
+            .send(Message::Notification(notification))?;
+        return Ok(());
+    };
+
+    for path in &cleared {
+        send(path, Vec::new())?;
+    }
+    *published = diagnostics.keys().cloned().collect();
+    for (path, diagnostics) in diagnostics {
+        send(&path, diagnostics)?;
+    }
+
+    return Ok(());
+}
+
+/// Module paths are canonical, so are the paths documents are looked up by.
+fn to_path(uri: &Url) -> PathBuf {
+    let path = uri
+        .to_file_path()
+        .unwrap_or_else(|_| PathBuf::from(uri.path()));
+    return path.canonicalize().unwrap_or(path);
+}
@@ -0,0 +1,5 @@
+pub mod command;
+mod workspace;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod tests {
+    use lantern_testing::load_fixture;
+    use lsp_server::{Connection, Message, Notification, Request, RequestId};
+    use lsp_types::{InitializeParams, Location, PublishDiagnosticsParams, Url};
+
+    use crate::commands::lsp::command::{serve, FIND_IMPORTERS_REQUEST};
+
+    #[test]
+    fn serves_diagnostics_and_importers() {
+        let root = load_fixture!("lsp_workspace").canonicalize().unwrap();
+        let entry_points = vec![root.join("index.ts")];
+        let (server, client) = Connection::memory();
+        let handle = std::thread::spawn(move || serve(&server, &entry_points).unwrap());
+
+        #[allow(deprecated)]
+        let params = InitializeParams::default();
+        let request = Request::new(RequestId::from(1), "initialize".to_string(), params);
+        client.sender.send(Message::Request(request)).unwrap();
+        match client.receiver.recv().unwrap() {
+            Message::Response(response) => assert!(response.error.is_none()),
+            message => panic!("unexpected message {:?}", message),
+        }
+        let initialized = Notification::new("initialized".to_string(), serde_json::json!({}));
+        client
+            .sender
+            .send(Message::Notification(initialized))
+            .unwrap();
+
+        let mut published = Vec::new();
+        for _ in 0..3 {
+            match client.receiver.recv().unwrap() {
+                Message::Notification(notification) => {
+                    assert_eq!(notification.method, "textDocument/publishDiagnostics");
+                    let params: PublishDiagnosticsParams =
+                        serde_json::from_value(notification.params).unwrap();
+                    published.push(params.uri.to_file_path().unwrap());
+                }
+                message => panic!("unexpected message {:?}", message),
+            }
+        }
+        published.sort();
+        assert_eq!(
+            published,
+            vec![root.join("a.ts"), root.join("b.ts"), root.join("index.ts")]
+        );
+
+        let uri = Url::from_file_path(root.join("b.ts")).unwrap();
+        let request = Request::new(
+            RequestId::from(2),
+            FIND_IMPORTERS_REQUEST.to_string(),
+            serde_json::json!({ "uri": uri }),
+        );
+        client.sender.send(Message::Request(request)).unwrap();
+        match client.receiver.recv().unwrap() {
+            Message::Response(response) => {
+                let locations: Vec<Location> =
+                    serde_json::from_value(response.result.unwrap()).unwrap();
+                assert_eq!(locations.len(), 1);
+                assert_eq!(locations[0].uri.to_file_path().unwrap(), root.join("a.ts"));
+            }
+            message => panic!("unexpected message {:?}", message),
+        }
+
+        // Edits are answered right away, diagnostics follow once the edits stop.
+        let a_uri = Url::from_file_path(root.join("a.ts")).unwrap();
+        for text in ["export const a = 2;\n", "export const a = 1;\n"] {
+            let open = Notification::new(
+                "textDocument/didOpen".to_string(),
+                serde_json::json!({
+                    "textDocument": { "uri": a_uri, "languageId": "typescript", "version": 1, "text": text }
+                }),
+            );
+            client.sender.send(Message::Notification(open)).unwrap();
+        }
+        let request = Request::new(
+            RequestId::from(3),
+            FIND_IMPORTERS_REQUEST.to_string(),
+            serde_json::json!({ "uri": uri }),
+        );
+        client.sender.send(Message::Request(request)).unwrap();
+        match client.receiver.recv().unwrap() {
+            Message::Response(response) => {
+                let locations: Vec<Location> =
+                    serde_json::from_value(response.result.unwrap()).unwrap();
+                assert!(locations.is_empty());
+            }
+            message => panic!("unexpected message {:?}", message),
+        }
+        let mut cleared = Vec::new();
+        for _ in 0..3 {
+            match client.receiver.recv().unwrap() {
+                Message::Notification(notification) => {
+                    let params: PublishDiagnosticsParams =
+                        serde_json::from_value(notification.params).unwrap();
+                    if params.diagnostics.is_empty() {
+                        cleared.push(params.uri.to_file_path().unwrap());
+                    }
+                }
+                message => panic!("unexpected message {:?}", message),
+            }
+        }
+        cleared.sort();
+        assert_eq!(cleared, vec![root.join("a.ts"), root.join("b.ts")]);
+
+        let shutdown = Request::new(RequestId::from(4), "shutdown".to_string(), ());
+        client.sender.send(Message::Request(shutdown)).unwrap();
+        client.receiver.recv().unwrap();
+        let exit = Notification::new("exit".to_string(), ());
+        client.sender.send(Message::Notification(exit)).unwrap();
+        handle.join().unwrap();
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use lantern_testing::load_fixture;
+    use lsp_types::{Diagnostic, NumberOrString, Position};
+
+    use crate::commands::lsp::workspace::Workspace;
+
+    fn fixture_path(name: &str) -> PathBuf {
+        return load_fixture!("lsp_workspace")
+            .join(name)
+            .canonicalize()
+            .unwrap();
+    }
+
+    fn get_workspace() -> Workspace {
+        return Workspace::new(&[fixture_path("index.ts")]).unwrap();
+    }
+
+    fn codes(diagnostics: &[Diagnostic]) -> Vec<String> {
+        let mut codes = diagnostics
+            .iter()
+            .map(|diagnostic| match &diagnostic.code {
+                Some(NumberOrString::String(code)) => code.clone(),
+                _ => String::new(),
+            })
+            .collect::<Vec<String>>();
+        codes.sort();
+        return codes;
+    }
+
+    #[test]
+    fn diagnostics() {
+        let mut workspace = get_workspace();
+        let diagnostics = workspace.get_diagnostics();
+
+        assert_eq!(
+            codes(&diagnostics[&fixture_path("a.ts")]),
+            vec!["import-cycle", "unused-export"]
+        );
+        assert_eq!(
+            codes(&diagnostics[&fixture_path("b.ts")]),
+            vec!["import-cycle"]
+        );
+        let index = &diagnostics[&fixture_path("index.ts")];
+        assert_eq!(codes(index), vec!["unresolved-import"]);
+        assert_eq!(index[0].range.start, Position::new(1, 18));
+        assert_eq!(index[0].range.end, Position::new(1, 29));
+    }
+
+    #[test]
+    fn open_documents_replace_files_on_disk() {
+        let mut workspace = get_workspace();
+        workspace.set_document(fixture_path("a.ts"), "export const a = 1;\n".to_string());
+        let diagnostics = workspace.get_diagnostics();
+        assert!(!diagnostics.contains_key(&fixture_path("a.ts")));
+        assert!(!diagnostics.contains_key(&fixture_path("b.ts")));
+
+        workspace.close_document(&fixture_path("a.ts"));
+        let diagnostics = workspace.get_diagnostics();
+        assert_eq!(
+            codes(&diagnostics[&fixture_path("a.ts")]),
+            vec!["import-cycle", "unused-export"]
+        );
+    }
+
+    #[test]
+    fn code_lenses() {
+        let mut workspace = get_workspace();
+        let lenses = workspace.get_code_lenses(&fixture_path("a.ts"));
+        assert_eq!(lenses.len(), 1);
+        assert_eq!(lenses[0].command.as_ref().unwrap().title, "used by 2 files");
+
+        let lenses = workspace.get_code_lenses(&fixture_path("index.ts"));
+        assert_eq!(lenses[0].command.as_ref().unwrap().title, "used by 0 files");
+    }
+
+    #[test]
+    fn find_importers() {
+        let mut workspace = get_workspace();
+        let mut locations = workspace
+            .find_importers(&fixture_path("a.ts"))
+            .into_iter()
+            .map(|location| {
+                return (
+                    location.uri.to_file_path().unwrap(),
+                    location.range.start.line,
+                );
+            })
+            .collect::<Vec<(PathBuf, u32)>>();
+        locations.sort();
+        assert_eq!(
+            locations,
+            vec![(fixture_path("b.ts"), 0), (fixture_path("index.ts"), 0)]
+        );
+    }
+}
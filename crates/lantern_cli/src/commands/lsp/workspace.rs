@@ -0,0 +1,277 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use color_eyre::eyre::Result;
+use lsp_types::{
+    CodeLens, Command, Diagnostic, DiagnosticSeverity, DiagnosticTag, Location, NumberOrString,
+    Position, Range, Url,
+};
+use oxc_span::Span;
+
+use lantern_dependency_graph::LanternFileDependencyMap;
+use lantern_formatters::findings_reporter::{LanternRule, LanternSeverity};
+use lantern_fs::{MemoryFileSystem, OsFileSystem, OverlayFileSystem};
+
+use crate::commands::cycles::find_cycles::find_cycles;
+use crate::commands::edge_annotation::display_path;
+use crate::commands::findings::{CYCLE_RULE, UNRESOLVED_IMPORT_RULE, UNUSED_EXPORT_RULE};
+use crate::commands::unused_exports::find_unused_exports::find_unused_exports;
+
+/// Client side command code lenses run to list the importers of a file.
+pub const FIND_IMPORTERS_COMMAND: &str = "lantern.findImporters";
+
+/// Analysis of the project as the editor sees it, open documents take precedence over the
+/// files on disk.
+pub struct Workspace {
+    entry_points: Vec<PathBuf>,
+    documents: HashMap<PathBuf, String>,
+    depgraph: LanternFileDependencyMap,
+    /// Documents changed since the last analysis.
+    is_stale: bool,
+}
+
+impl Workspace {
+    pub fn new(entry_points: &[PathBuf]) -> Result<Self> {
+        let documents = HashMap::new();
+        let depgraph = build_depgraph(entry_points, &documents)?;
+        return Ok(Self {
+            entry_points: entry_points.to_vec(),
+            documents,
+            depgraph,
+            is_stale: false,
+        });
+    }
+
+    /// The project is analysed again on the next query, so a burst of edits only costs
+    /// one rebuild.
+    pub fn set_document(&mut self, path: PathBuf, text: String) {
+        self.documents.insert(path, text);
+        self.is_stale = true;
+    }
+
+    /// Goes back to the file on disk.
+    pub fn close_document(&mut self, path: &Path) {
+        if self.documents.remove(path).is_some() {
+            self.is_stale = true;
+        }
+    }
+
+    /// Keeps the last good analysis while a document doesn't parse.
+    fn refresh(&mut self) {
+        if !self.is_stale {
+            return;
+        }
+        self.is_stale = false;
+        match build_depgraph(&self.entry_points, &self.documents) {
+            Ok(depgraph) => self.depgraph = depgraph,
+            Err(err) => eprintln!("{}", err),
+        }
+    }
+
+    /// Diagnostics of every file with problems, files in node_modules are left out.
+    pub fn get_diagnostics(&mut self) -> BTreeMap<PathBuf, Vec<Diagnostic>> {
+        self.refresh();
+        let mut diagnostics: BTreeMap<PathBuf, Vec<Diagnostic>> = BTreeMap::new();
+        let ln_map = &mut self.depgraph.symbols_map;
+
+        let unused_exports = find_unused_exports(ln_map).unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            return Vec::new();
+        });
+        for symbol in unused_exports {
+            let message = match symbol.get_name() {
+                Some(name) => format!("unused export: {}", name),
+                None => "unused export".to_owned(),
+            };
+            let mut diagnostic = to_diagnostic(
+                UNUSED_EXPORT_RULE,
+                to_range(
+                    ln_map.get_module_source(symbol.module_id),
+                    symbol.get_span(),
+                ),
+                message,
+            );
+            diagnostic.tags = Some(vec![DiagnosticTag::UNNECESSARY]);
+            let path = ln_map.get_module_path(symbol.module_id).clone();
+            diagnostics.entry(path).or_default().push(diagnostic);
+        }
+
+        for unresolved_import in ln_map.unresolved_imports.clone() {
+            let source = ln_map.get_module_source(unresolved_import.module_id);
+            let diagnostic = to_diagnostic(
+                UNRESOLVED_IMPORT_RULE,
+                to_range(source, &unresolved_import.span),
+                format!("unresolved import: {}", unresolved_import.source),
+            );
+            let path = ln_map.get_module_path(unresolved_import.module_id).clone();
+            diagnostics.entry(path).or_default().push(diagnostic);
+        }
+
+        for cycle in find_cycles(&self.depgraph) {
+            let mut hops = cycle
+                .iter()
+                .map(|module_id| {
+                    display_path(self.depgraph.symbols_map.get_module_path(*module_id))
+                })
+                .collect::<Vec<String>>();
+            hops.push(hops[0].clone());
+            let message = format!("import cycle: {}", hops.join(" → "));
+
+            for (pos, from) in cycle.iter().enumerate() {
+                let to = cycle[(pos + 1) % cycle.len()];
+                let span = match self.get_import_span(*from, to) {
+                    Some(span) => span,
+                    None => continue,
+                };
+                let ln_map = &mut self.depgraph.symbols_map;
+                let diagnostic = to_diagnostic(
+                    CYCLE_RULE,
+                    to_range(ln_map.get_module_source(*from), &span),
+                    message.clone(),
+                );
+                let path = ln_map.get_module_path(*from).clone();
+                diagnostics.entry(path).or_default().push(diagnostic);
+            }
+        }
+
+        diagnostics.retain(|path, _| {
+            return !path
+                .components()
+                .any(|component| component.as_os_str() == "node_modules");
+        });
+        return diagnostics;
+    }
+
+    /// "used by N files" at the top of the file.
+    pub fn get_code_lenses(&mut self, path: &Path) -> Vec<CodeLens> {
+        self.refresh();
+        let module_id = match self.get_module_id(path) {
+            Some(module_id) => module_id,
+            None => return Vec::new(),
+        };
+        let importers = self
+            .depgraph
+            .inverse_dependency_map
+            .get(&module_id)
+            .map_or(0, |importers| importers.len());
+        let title = if importers == 1 {
+            "used by 1 file".to_string()
+        } else {
+            format!("used by {} files", importers)
+        };
+
+        return vec![CodeLens {
+            range: Range::default(),
+            command: Some(Command {
+                title,
+                command: FIND_IMPORTERS_COMMAND.to_string(),
+                arguments: Url::from_file_path(path)
+                    .ok()
+                    .map(|uri| vec![serde_json::json!(uri)]),
+            }),
+            data: None,
+        }];
+    }
+
+    /// Import statements of other files pointing at `path`, one per importing file.
+    pub fn find_importers(&mut self, path: &Path) -> Vec<Location> {
+        self.refresh();
+        let module_id = match self.get_module_id(path) {
+            Some(module_id) => module_id,
+            None => return Vec::new(),
+        };
+        let mut importers = self
+            .depgraph
+            .inverse_dependency_map
+            .get(&module_id)
+            .map(|importers| importers.iter().copied().collect::<Vec<usize>>())
+            .unwrap_or_default();
+        importers.sort();
+
+        let mut locations = Vec::new();
+        for importer in importers {
+            let span = match self.get_import_span(importer, module_id) {
+                Some(span) => span,
+                None => continue,
+            };
+            let ln_map = &mut self.depgraph.symbols_map;
+            let uri = match Url::from_file_path(ln_map.get_module_path(importer)) {
+                Ok(uri) => uri,
+                Err(_) => continue,
+            };
+            locations.push(Location {
+                uri,
+                range: to_range(ln_map.get_module_source(importer), &span),
+            });
+        }
+        return locations;
+    }
+
+    fn get_module_id(&self, path: &Path) -> Option<usize> {
+        return self.depgraph.symbols_map.get_module_id(path.to_str()?);
+    }
+
+    /// Span of the source string of the first import in `from` pointing at `to`.
+    fn get_import_span(&self, from: usize, to: usize) -> Option<Span> {
+        let symbol = self
+            .depgraph
+            .get_dependency_symbols(from, to)
+            .into_iter()
+            .next()?;
+        return match symbol.get_file_reference() {
+            Some(file_ref) => Some(file_ref.span),
+            None => Some(*symbol.get_span()),
+        };
+    }
+}
+
+fn build_depgraph(
+    entry_points: &[PathBuf],
+    documents: &HashMap<PathBuf, String>,
+) -> Result<LanternFileDependencyMap> {
+    let mut upper = MemoryFileSystem::new();
+    for (path, text) in documents {
+        upper.add_file(path, text.clone());
+    }
+    let fs = OverlayFileSystem::new(Arc::new(upper), Arc::new(OsFileSystem));
+    let ln_map = lantern_symbols_map::build_symbols_map_with_fs(entry_points, Arc::new(fs))?;
+    let mut depgraph = LanternFileDependencyMap::new(ln_map);
+    depgraph.build_dependency_graph();
+    return Ok(depgraph);
+}
+
+fn to_diagnostic(rule: LanternRule, range: Range, message: String) -> Diagnostic {
+    let severity = match rule.severity {
+        LanternSeverity::Note => DiagnosticSeverity::INFORMATION,
+        LanternSeverity::Warning => DiagnosticSeverity::WARNING,
+        LanternSeverity::Error => DiagnosticSeverity::ERROR,
+    };
+    return Diagnostic {
+        range,
+        severity: Some(severity),
+        code: Some(NumberOrString::String(rule.id.to_string())),
+        source: Some("lantern".to_string()),
+        message,
+        ..Default::default()
+    };
+}
+
+pub fn to_range(source: &str, span: &Span) -> Range {
+    return Range {
+        start: to_position(source, span.start),
+        end: to_position(source, span.end),
+    };
+}
+
+/// LSP positions are 0-based and count UTF-16 code units within the line.
+fn to_position(source: &str, offset: u32) -> Position {
+    let before = &source[0..offset as usize];
+    let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+    return Position {
+        line: before.matches('\n').count() as u32,
+        character: before[line_start..].encode_utf16().count() as u32,
+    };
+}
@@ -0,0 +1,128 @@
+use std::path::PathBuf;
+
+use clap::ValueEnum;
+use color_eyre::eyre::Result;
+
+use lantern_dependency_graph::LanternFileDependencyMap;
+
+use crate::commands::depgraph::command::build_graph;
+use crate::commands::grouping::{get_grouping, GroupBy};
+use crate::commands::metrics::compute_metrics::{
+    compute_metrics, sort_metrics, MetricsSort, ModuleMetrics,
+};
+use crate::commands::source::SourceOptions;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum MetricsFormat {
+    Table,
+    Csv,
+    Json,
+}
+
+pub struct MetricsOptions {
+    pub group_by: Option<GroupBy>,
+    pub depth: Option<usize>,
+    pub sort: MetricsSort,
+    /// Only output the first rows after sorting.
+    pub limit: Option<usize>,
+    pub format: MetricsFormat,
+    /// Directory paths are relative to, defaults to the current directory.
+    pub root: Option<PathBuf>,
+    /// Revision to read files from.
+    pub source: SourceOptions,
+}
+
+pub fn run(entry_points: &[PathBuf], options: &MetricsOptions) -> Result<()> {
+    let ln_map = options.source.build_symbols_map(entry_points)?;
+    let mut depgraph = LanternFileDependencyMap::new(ln_map);
+    depgraph.build_dependency_graph();
+
+    let root = match &options.root {
+        Some(root) => root.canonicalize()?,
+        None => std::env::current_dir()?.canonicalize()?,
+    };
+    let graph = build_graph(&depgraph, &root)?;
+
+    let mut metrics = compute_metrics(&graph, get_grouping(options.group_by, options.depth));
+    sort_metrics(&mut metrics, options.sort);
+    if let Some(limit) = options.limit {
+        metrics.truncate(limit);
+    }
+
+    match options.format {
+        MetricsFormat::Table => print_table(&metrics),
+        MetricsFormat::Csv => print!("{}", to_csv(&metrics)),
+        MetricsFormat::Json => println!("{}", serde_json::to_string_pretty(&metrics)?),
+    }
+
+    return Ok(());
+}
+
+const COLUMNS: [&str; 7] = [
+    "path",
+    "fan_in",
+    "fan_out",
+    "instability",
+    "depth",
+    "exports",
+    "unused_exports",
+];
+
+fn to_row(metrics: &ModuleMetrics) -> Vec<String> {
+    return vec![
+        metrics.path.clone(),
+        metrics.fan_in.to_string(),
+        metrics.fan_out.to_string(),
+        format!("{:.2}", metrics.instability),
+        metrics.depth.map(|d| d.to_string()).unwrap_or_default(),
+        metrics.exports.to_string(),
+        metrics.unused_exports.to_string(),
+    ];
+}
+
+pub fn to_csv(metrics: &Vec<ModuleMetrics>) -> String {
+    let mut res = vec![COLUMNS.join(",")];
+    for row in metrics {
+        let row = to_row(row)
+            .iter()
+            .map(|value| {
+                if value.contains([',', '"', '\n']) {
+                    return format!("\"{}\"", value.replace('"', "\"\""));
+                }
+                return value.clone();
+            })
+            .collect::<Vec<String>>();
+        res.push(row.join(","));
+    }
+    return res.join("\n") + "\n";
+}
+
+fn print_table(metrics: &[ModuleMetrics]) {
+    let rows = metrics.iter().map(to_row).collect::<Vec<Vec<String>>>();
+    let mut widths = COLUMNS.map(|column| column.len());
+    for row in &rows {
+        for (idx, value) in row.iter().enumerate() {
+            widths[idx] = widths[idx].max(value.chars().count());
+        }
+    }
+
+    let print_row = |row: Vec<String>| {
+        let cells = row
+            .iter()
+            .enumerate()
+            .map(|(idx, value)| {
+                // Paths are left aligned, numbers right aligned.
+                if idx == 0 {
+                    return format!("{:<width$}", value, width = widths[idx]);
+                }
+                return format!("{:>width$}", value, width = widths[idx]);
+            })
+            .collect::<Vec<String>>();
+        println!("{}", cells.join("  ").trim_end());
+    };
+
+    print_row(COLUMNS.map(String::from).to_vec());
+    for row in rows {
+        print_row(row);
+    }
+}
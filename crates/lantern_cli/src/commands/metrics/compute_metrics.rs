@@ -0,0 +1,148 @@
+use std::collections::{BTreeMap, HashSet, VecDeque};
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use lantern_dependency_graph::graph::{LanternGraph, LanternGraphGrouping};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ModuleMetrics {
+    /// File path, or group name when metrics are grouped.
+    pub path: String,
+    /// Number of modules importing this one (Ca).
+    pub fan_in: usize,
+    /// Number of modules this one imports (Ce).
+    pub fan_out: usize,
+    /// Ce / (Ca + Ce), 0 for isolated modules.
+    pub instability: f64,
+    /// Fewest imports between an entry and the module, none when no entry reaches it.
+    pub depth: Option<usize>,
+    pub exports: usize,
+    pub unused_exports: usize,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum MetricsSort {
+    Path,
+    FanIn,
+    FanOut,
+    Instability,
+    Depth,
+    Exports,
+    UnusedExports,
+}
+
+#[derive(Default)]
+struct Totals {
+    dependents: HashSet<String>,
+    dependencies: HashSet<String>,
+    depth: Option<usize>,
+    exports: usize,
+    unused_exports: usize,
+}
+
+/// Metrics per node of the graph, or per group of nodes. Imports within a group are
+/// not counted towards its fan-in and fan-out.
+pub fn compute_metrics(
+    graph: &LanternGraph,
+    grouping: Option<LanternGraphGrouping>,
+) -> Vec<ModuleMetrics> {
+    let names = match grouping {
+        Some(grouping) => graph.get_node_groups(grouping),
+        None => graph
+            .nodes
+            .iter()
+            .map(|node| node.path.display().to_string())
+            .collect(),
+    };
+    let depths = get_depths(graph);
+
+    let mut totals: BTreeMap<&str, Totals> = BTreeMap::new();
+    for node in &graph.nodes {
+        let total = totals.entry(&names[node.id]).or_default();
+        total.depth = match (total.depth, depths[node.id]) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        total.exports += node.exports.len();
+        total.unused_exports += node.exports.iter().filter(|e| e.is_unused).count();
+    }
+    for edge in &graph.edges {
+        let (from, to) = (&names[edge.from], &names[edge.to]);
+        if from == to {
+            continue;
+        }
+        totals
+            .get_mut(from.as_str())
+            .unwrap()
+            .dependencies
+            .insert(to.clone());
+        totals
+            .get_mut(to.as_str())
+            .unwrap()
+            .dependents
+            .insert(from.clone());
+    }
+
+    return totals
+        .into_iter()
+        .map(|(name, total)| {
+            let fan_in = total.dependents.len();
+            let fan_out = total.dependencies.len();
+            let instability = if fan_in + fan_out == 0 {
+                0.0
+            } else {
+                fan_out as f64 / (fan_in + fan_out) as f64
+            };
+            return ModuleMetrics {
+                path: name.to_owned(),
+                fan_in,
+                fan_out,
+                instability,
+                depth: total.depth,
+                exports: total.exports,
+                unused_exports: total.unused_exports,
+            };
+        })
+        .collect();
+}
+
+/// Sorts by the given column, numbers in descending order and paths in ascending order.
+/// Ties are ordered by path.
+pub fn sort_metrics(metrics: &mut [ModuleMetrics], sort: MetricsSort) {
+    metrics.sort_by(|a, b| {
+        let order = match sort {
+            MetricsSort::Path => std::cmp::Ordering::Equal,
+            MetricsSort::FanIn => b.fan_in.cmp(&a.fan_in),
+            MetricsSort::FanOut => b.fan_out.cmp(&a.fan_out),
+            MetricsSort::Instability => b.instability.total_cmp(&a.instability),
+            MetricsSort::Depth => b.depth.cmp(&a.depth),
+            MetricsSort::Exports => b.exports.cmp(&a.exports),
+            MetricsSort::UnusedExports => b.unused_exports.cmp(&a.unused_exports),
+        };
+        return order.then_with(|| a.path.cmp(&b.path));
+    });
+}
+
+// BFS from all entries at once.
+fn get_depths(graph: &LanternGraph) -> Vec<Option<usize>> {
+    let dependency_map = graph.get_dependency_map();
+    let mut depths = vec![None; graph.nodes.len()];
+    let mut queue = VecDeque::new();
+    for node in graph.nodes.iter().filter(|node| node.is_entry) {
+        depths[node.id] = Some(0);
+        queue.push_back(node.id);
+    }
+
+    while let Some(cur) = queue.pop_front() {
+        let depth = depths[cur].unwrap();
+        for to in dependency_map.get(&cur).unwrap_or(&HashSet::new()) {
+            if depths[*to].is_none() {
+                depths[*to] = Some(depth + 1);
+                queue.push_back(*to);
+            }
+        }
+    }
+
+    return depths;
+}
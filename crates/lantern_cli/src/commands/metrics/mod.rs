@@ -0,0 +1,5 @@
+pub mod command;
+pub mod compute_metrics;
+
+#[cfg(test)]
+mod tests;
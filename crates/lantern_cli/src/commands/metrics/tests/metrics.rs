@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use lantern_dependency_graph::graph::{LanternGraph, LanternGraphGrouping};
+
+    use crate::commands::depgraph::command::build_graph;
+    use crate::commands::metrics::command::to_csv;
+    use crate::commands::metrics::compute_metrics::{
+        compute_metrics, sort_metrics, MetricsSort, ModuleMetrics,
+    };
+    use crate::commands::test_utils::{build_depgraph, fixture_root};
+
+    fn get_graph(fixture: &str, entries: &[&str]) -> LanternGraph {
+        let depgraph = build_depgraph(fixture, entries);
+        return build_graph(&depgraph, &fixture_root(fixture)).unwrap();
+    }
+
+    fn find<'a>(metrics: &'a [ModuleMetrics], path: &str) -> &'a ModuleMetrics {
+        return metrics.iter().find(|m| m.path == path).unwrap();
+    }
+
+    #[test]
+    fn module_metrics() {
+        let graph = get_graph("depgraph_focus", &["index.ts", "other.ts"]);
+        let metrics = compute_metrics(&graph, None);
+        assert_eq!(metrics.len(), 6);
+
+        let b = find(&metrics, "b.ts");
+        assert_eq!((b.fan_in, b.fan_out), (2, 1));
+        assert!((b.instability - 1.0 / 3.0).abs() < f64::EPSILON);
+        assert_eq!(b.depth, Some(1));
+        assert_eq!(b.exports, 1);
+
+        let index = find(&metrics, "index.ts");
+        assert_eq!((index.instability, index.depth), (1.0, Some(0)));
+        assert_eq!(find(&metrics, "c.ts").depth, Some(2));
+    }
+
+    #[test]
+    fn unused_exports() {
+        let graph = get_graph("depgraph_edge_kinds", &["index.ts"]);
+        let metrics = compute_metrics(&graph, None);
+        let config = find(&metrics, "config.ts");
+        assert_eq!((config.exports, config.unused_exports), (2, 1));
+    }
+
+    #[test]
+    fn directory_metrics() {
+        let graph = get_graph("depgraph_focus", &["index.ts", "other.ts"]);
+        let metrics = compute_metrics(&graph, Some(LanternGraphGrouping::Dir(None)));
+        assert_eq!(metrics.len(), 2);
+
+        let root = find(&metrics, ".");
+        assert_eq!((root.fan_in, root.fan_out, root.exports), (0, 1, 3));
+        let vendor = find(&metrics, "vendor");
+        assert_eq!(
+            (vendor.fan_in, vendor.fan_out, vendor.depth),
+            (1, 0, Some(2))
+        );
+    }
+
+    #[test]
+    fn sorting_and_csv() {
+        let graph = get_graph("depgraph_focus", &["index.ts", "other.ts"]);
+        let mut metrics = compute_metrics(&graph, None);
+        sort_metrics(&mut metrics, MetricsSort::FanIn);
+        metrics.truncate(2);
+
+        assert_eq!(
+            to_csv(&metrics),
+            "path,fan_in,fan_out,instability,depth,exports,unused_exports\n\
+             b.ts,2,1,0.33,1,1,0\n\
+             a.ts,1,2,0.67,1,1,0\n"
+        );
+    }
+}
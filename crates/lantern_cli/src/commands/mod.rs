@@ -1,5 +1,21 @@
 pub mod affected;
+pub mod baseline;
+pub mod check_boundaries;
 pub mod cycles;
 pub mod depgraph;
+pub mod diff;
+pub mod dump;
+mod edge_annotation;
+pub mod export_db;
 pub mod files_with_reexports;
+pub mod findings;
+pub mod grouping;
+pub mod lsp;
+pub mod metrics;
+mod path_matcher;
+pub mod size;
+pub mod source;
+#[cfg(test)]
+pub mod test_utils;
 pub mod unused_exports;
+pub mod why;
@@ -0,0 +1,42 @@
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{Result, WrapErr};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+
+/// Matches paths relative to the project root against globs. Patterns without a leading
+/// `**/` match at any depth, so `*.test.ts` matches `src/a.test.ts`.
+pub struct PathMatcher {
+    root: PathBuf,
+    globs: GlobSet,
+}
+
+impl PathMatcher {
+    pub fn new(root: &Path, patterns: &Vec<String>) -> Result<Self> {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in patterns {
+            let pattern = pattern.trim_start_matches("./");
+            let pattern = if pattern.starts_with("**/") || pattern.starts_with('/') {
+                pattern.to_owned()
+            } else {
+                format!("**/{}", pattern)
+            };
+            builder.add(
+                Glob::new(&pattern).wrap_err_with(|| format!("Invalid pattern {:?}", pattern))?,
+            );
+        }
+
+        return Ok(Self {
+            root: root.to_path_buf(),
+            globs: builder.build()?,
+        });
+    }
+
+    pub fn is_match(&self, path: &Path) -> bool {
+        let relative_path = path.strip_prefix(&self.root).unwrap_or(path);
+        return self.globs.is_match(relative_path);
+    }
+
+    pub fn get_root(&self) -> &Path {
+        return &self.root;
+    }
+}
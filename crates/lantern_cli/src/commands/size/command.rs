@@ -0,0 +1,90 @@
+use std::path::PathBuf;
+
+use clap::ValueEnum;
+use color_eyre::eyre::Result;
+
+use lantern_dependency_graph::LanternFileDependencyMap;
+
+use crate::commands::edge_annotation::display_path;
+use crate::commands::size::compute_size::{
+    compute_entry_size, format_bytes, EntrySize, ModuleSize,
+};
+use crate::commands::source::SourceOptions;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum SizeFormat {
+    Text,
+    Json,
+}
+
+pub struct SizeOptions {
+    /// Number of top contributors and retained sizes to show per entry.
+    pub top: usize,
+    /// Split the total into first-party and node_modules sources.
+    pub split: bool,
+    pub format: SizeFormat,
+    /// Revision to read files from.
+    pub source: SourceOptions,
+}
+
+pub fn run(entry_points: &[PathBuf], options: &SizeOptions) -> Result<()> {
+    let ln_map = options.source.build_symbols_map(entry_points)?;
+    let mut depgraph = LanternFileDependencyMap::new(ln_map);
+    depgraph.build_dependency_graph();
+
+    let entries = depgraph
+        .symbols_map
+        .modules
+        .iter()
+        .enumerate()
+        .filter(|(_, module)| module.is_entry)
+        .map(|(module_id, _)| module_id)
+        .collect::<Vec<usize>>();
+    let sizes = entries
+        .iter()
+        .map(|entry_id| compute_entry_size(&mut depgraph, *entry_id, options.top))
+        .collect::<Vec<EntrySize>>();
+
+    match options.format {
+        SizeFormat::Text => {
+            for size in &sizes {
+                print_entry_size(size, options.split);
+            }
+        }
+        SizeFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&sizes)?);
+        }
+    }
+
+    return Ok(());
+}
+
+fn print_entry_size(size: &EntrySize, split: bool) {
+    println!(
+        "{}: {} in {} modules",
+        display_path(&size.entry),
+        format_bytes(size.total),
+        size.modules
+    );
+    if split {
+        println!("  first-party:  {}", format_bytes(size.first_party));
+        println!("  node_modules: {}", format_bytes(size.node_modules));
+    }
+
+    let print_modules = |title: &str, modules: &Vec<ModuleSize>| {
+        if modules.is_empty() {
+            return;
+        }
+        println!("  {}:", title);
+        for module in modules {
+            println!(
+                "    {:>10}  {}",
+                format_bytes(module.size),
+                display_path(&module.path)
+            );
+        }
+    };
+    print_modules("Largest modules", &size.largest);
+    print_modules("Retained size", &size.retained);
+    println!();
+}
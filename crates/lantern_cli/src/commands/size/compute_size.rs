@@ -0,0 +1,103 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use serde::Serialize;
+
+use lantern_dependency_graph::algorithms::dominators::immediate_dominators;
+use lantern_dependency_graph::LanternFileDependencyMap;
+
+#[derive(Debug, Serialize)]
+pub struct EntrySize {
+    pub entry: PathBuf,
+    /// Number of modules reachable from the entry, including the entry itself.
+    pub modules: usize,
+    /// Source bytes of all reachable modules.
+    pub total: usize,
+    pub first_party: usize,
+    pub node_modules: usize,
+    /// Reachable modules with the largest sources.
+    pub largest: Vec<ModuleSize>,
+    /// Modules with the most bytes that would no longer be reachable from the entry if
+    /// the module stopped being imported.
+    pub retained: Vec<ModuleSize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ModuleSize {
+    pub path: PathBuf,
+    pub size: usize,
+}
+
+pub fn compute_entry_size(
+    depgraph: &mut LanternFileDependencyMap,
+    entry_id: usize,
+    top: usize,
+) -> EntrySize {
+    let idoms = immediate_dominators(entry_id, &depgraph.dependency_map);
+
+    let mut sizes = HashMap::new();
+    let (mut first_party, mut node_modules) = (0, 0);
+    for (module_id, _) in &idoms {
+        let size = depgraph.symbols_map.get_module_size(*module_id);
+        sizes.insert(*module_id, size);
+        if is_node_module(depgraph.symbols_map.get_module_path(*module_id)) {
+            node_modules += size;
+        } else {
+            first_party += size;
+        }
+    }
+
+    // Nodes come after their dominators, so walking backwards adds every module's retained
+    // size to its dominator only once it's complete.
+    let mut retained = sizes.clone();
+    for (module_id, idom) in idoms.iter().rev() {
+        if let Some(idom) = idom {
+            let size = retained[module_id];
+            *retained.get_mut(idom).unwrap() += size;
+        }
+    }
+    retained.remove(&entry_id);
+
+    let to_sorted = |sizes: HashMap<usize, usize>| {
+        let mut sizes = sizes.into_iter().collect::<Vec<(usize, usize)>>();
+        sizes.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        return sizes
+            .into_iter()
+            .take(top)
+            .map(|(module_id, size)| ModuleSize {
+                path: depgraph.symbols_map.get_module_path(module_id).clone(),
+                size,
+            })
+            .collect::<Vec<ModuleSize>>();
+    };
+
+    return EntrySize {
+        entry: depgraph.symbols_map.get_module_path(entry_id).clone(),
+        modules: idoms.len(),
+        total: first_party + node_modules,
+        first_party,
+        node_modules,
+        largest: to_sorted(sizes),
+        retained: to_sorted(retained),
+    };
+}
+
+fn is_node_module(path: &Path) -> bool {
+    return path
+        .components()
+        .any(|component| component.as_os_str() == "node_modules");
+}
+
+/// "1536" -> "1.5 KB"
+pub fn format_bytes(bytes: usize) -> String {
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let kb = bytes as f64 / 1024.0;
+    if kb < 1024.0 {
+        return format!("{:.1} KB", kb);
+    }
+    return format!("{:.1} MB", kb / 1024.0);
+}
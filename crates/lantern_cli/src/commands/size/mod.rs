@@ -0,0 +1,5 @@
+pub mod command;
+mod compute_size;
+
+#[cfg(test)]
+mod tests;
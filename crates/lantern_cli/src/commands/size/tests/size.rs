@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use lantern_testing::load_fixture;
+
+    use crate::commands::size::compute_size::{compute_entry_size, format_bytes, EntrySize};
+    use crate::commands::test_utils::{build_depgraph, fixture_root};
+
+    fn get_size(top: usize) -> EntrySize {
+        let mut depgraph = build_depgraph("size_report", &["index.ts"]);
+        let entry = fixture_root("size_report").join("index.ts");
+        let entry_id = depgraph
+            .symbols_map
+            .get_module_id(entry.to_str().unwrap())
+            .unwrap();
+        return compute_entry_size(&mut depgraph, entry_id, top);
+    }
+
+    fn file_size(path: &str) -> usize {
+        let path = load_fixture!("size_report").join(path);
+        return std::fs::metadata(path).unwrap().len() as usize;
+    }
+
+    fn file_name(path: &Path) -> String {
+        let root = fixture_root("size_report");
+        return path
+            .strip_prefix(root)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+    }
+
+    #[test]
+    fn total_size() {
+        let size = get_size(10);
+        let first_party = ["index.ts", "a.ts", "b.ts", "c.ts", "d.ts"]
+            .iter()
+            .map(|path| file_size(path))
+            .sum::<usize>();
+        let node_modules = file_size("node_modules/dep/index.ts");
+
+        assert_eq!(size.modules, 6);
+        assert_eq!(size.first_party, first_party);
+        assert_eq!(size.node_modules, node_modules);
+        assert_eq!(size.total, first_party + node_modules);
+        assert_eq!(size.largest.len(), 6);
+    }
+
+    #[test]
+    fn retained_size() {
+        let size = get_size(10);
+        let retained = size
+            .retained
+            .iter()
+            .map(|module| (file_name(&module.path), module.size))
+            .collect::<Vec<(String, usize)>>();
+
+        // c.ts is imported by both a.ts and b.ts, so it's retained by neither of them.
+        let a = file_size("a.ts") + file_size("d.ts") + file_size("node_modules/dep/index.ts");
+        assert_eq!(retained[0], ("a.ts".to_string(), a));
+        assert!(retained.contains(&("b.ts".to_string(), file_size("b.ts"))));
+        assert!(retained.contains(&("c.ts".to_string(), file_size("c.ts"))));
+        assert!(!retained.iter().any(|(path, _)| path == "index.ts"));
+    }
+
+    #[test]
+    fn top_contributors() {
+        let size = get_size(2);
+        assert_eq!(size.largest.len(), 2);
+        assert_eq!(file_name(&size.largest[0].path), "d.ts");
+        assert_eq!(size.retained.len(), 2);
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MB");
+    }
+}
@@ -0,0 +1,91 @@
+use std::{
+    io,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use clap::Args;
+use color_eyre::eyre::Result;
+
+use lantern_fs::{FileSystem, Metadata, OsFileSystem, OverlayFileSystem};
+use lantern_git::GitFileSystem;
+use lantern_symbols_map::symbols_map::LNSymbolsMap;
+
+/// Where project files are read from.
+#[derive(Debug, Clone, Default, Args)]
+pub struct SourceOptions {
+    /// Read files from this git revision instead of the working tree, e.g. main or a commit
+    /// sha
+    #[arg(long)]
+    pub rev: Option<String>,
+}
+
+impl SourceOptions {
+    pub fn build_symbols_map(&self, entry_points: &[PathBuf]) -> Result<LNSymbolsMap> {
+        let rev = match &self.rev {
+            Some(rev) => rev,
+            None => return lantern_symbols_map::build_symbols_map(entry_points),
+        };
+
+        // Entry points may only exist in the revision, so they are made absolute without
+        // touching the disk.
+        let cwd = std::env::current_dir()?.canonicalize()?;
+        let entry_points = entry_points
+            .iter()
+            .map(|entry_point| cwd.join(entry_point))
+            .collect::<Vec<PathBuf>>();
+        let repo_path = entry_points
+            .first()
+            .and_then(|entry_point| entry_point.ancestors().find(|dir| dir.is_dir()))
+            .unwrap_or(&cwd);
+        let git = GitFileSystem::open(repo_path, rev)?;
+        // node_modules aren't committed, installed packages are read from the disk.
+        let fs = OverlayFileSystem::new(Arc::new(git), Arc::new(NodeModulesFileSystem));
+        return lantern_symbols_map::build_symbols_map_with_fs(&entry_points, Arc::new(fs));
+    }
+}
+
+#[derive(Debug)]
+struct NodeModulesFileSystem;
+
+impl NodeModulesFileSystem {
+    fn check(&self, path: &Path) -> io::Result<()> {
+        let in_node_modules = path
+            .components()
+            .any(|component| component == Component::Normal("node_modules".as_ref()));
+        if !in_node_modules {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{} not found", path.display()),
+            ));
+        }
+        return Ok(());
+    }
+}
+
+impl FileSystem for NodeModulesFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        self.check(path)?;
+        return OsFileSystem.read_to_string(path);
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<Metadata> {
+        self.check(path)?;
+        return OsFileSystem.metadata(path);
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<Metadata> {
+        self.check(path)?;
+        return OsFileSystem.symlink_metadata(path);
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        self.check(path)?;
+        return OsFileSystem.canonicalize(path);
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        self.check(path)?;
+        return OsFileSystem.read_dir(path);
+    }
+}
@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+
+use lantern_dependency_graph::LanternFileDependencyMap;
+use lantern_symbols_map::symbols_map::LNSymbolsMap;
+use lantern_testing::{load_fixture, FixtureProject};
+
+/// Canonical path of a fixture directory, the root its module paths start with.
+pub fn fixture_root(fixture: &str) -> PathBuf {
+    return load_fixture!(fixture).canonicalize().unwrap();
+}
+
+/// Dependency graph of a fixture directory, entries are relative to it.
+pub fn build_depgraph(fixture: &str, entries: &[&str]) -> LanternFileDependencyMap {
+    let entry_points = entries
+        .iter()
+        .map(|entry| load_fixture!(fixture).join(entry))
+        .collect::<Vec<PathBuf>>();
+    return to_depgraph(lantern_symbols_map::build_symbols_map(&entry_points).unwrap());
+}
+
+/// Dependency graph of a project declared with `fixture_project!`.
+pub fn build_project_depgraph(
+    project: &FixtureProject,
+    entries: &[&str],
+) -> LanternFileDependencyMap {
+    let entry_points = entries
+        .iter()
+        .map(|entry| project.path(entry))
+        .collect::<Vec<PathBuf>>();
+    let ln_map =
+        lantern_symbols_map::build_symbols_map_with_fs(&entry_points, project.file_system())
+            .unwrap();
+    return to_depgraph(ln_map);
+}
+
+fn to_depgraph(ln_map: LNSymbolsMap) -> LanternFileDependencyMap {
+    let mut depgraph = LanternFileDependencyMap::new(ln_map);
+    depgraph.build_dependency_graph();
+    return depgraph;
+}
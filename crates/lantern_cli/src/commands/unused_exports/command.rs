@@ -3,15 +3,72 @@ use std::{collections::HashMap, path::PathBuf};
 use color_eyre::eyre::Result;
 
 use lantern_code_annotation::CodeAnnotation;
+use lantern_formatters::findings_reporter::{LanternFinding, LanternSeverity};
 
+use crate::commands::baseline::{
+    apply_baseline, export_key, get_baseline_root, print_fixed_entries, Baseline, BaselineOptions,
+};
+use crate::commands::findings::{
+    get_reporter, unused_export_finding, ReportFormat, UNUSED_EXPORT_RULE,
+};
+use crate::commands::source::SourceOptions;
 use crate::commands::unused_exports::find_unused_exports::find_unused_exports;
 
-pub fn run(entry_points: &Vec<PathBuf>) -> Result<()> {
-    let mut ln_map = lantern_symbols_map::build_symbols_map(&entry_points)?;
+pub struct UnusedExportsOptions {
+    pub format: ReportFormat,
+    /// Only report unused exports missing from this baseline.
+    pub baseline: Option<BaselineOptions>,
+    /// Revision to read files from.
+    pub source: SourceOptions,
+}
+
+/// Returns severities of the reported unused exports.
+pub fn run(
+    entry_points: &[PathBuf],
+    options: &UnusedExportsOptions,
+) -> Result<Vec<LanternSeverity>> {
+    let mut ln_map = options.source.build_symbols_map(entry_points)?;
     let mut annotations: HashMap<usize, CodeAnnotation> = HashMap::new();
-    let unused_exports = find_unused_exports(&ln_map)?;
-    let total = unused_exports.len();
+    let mut unused_exports = find_unused_exports(&ln_map)?;
+
+    let mut fixed = Vec::new();
+    let mut baselined = 0;
+    if let Some(baseline_options) = &options.baseline {
+        let root = get_baseline_root(&baseline_options.path)?;
+        let keys = unused_exports
+            .iter()
+            .map(|symbol| export_key(&ln_map, symbol, &root))
+            .collect::<Vec<String>>();
+
+        if baseline_options.update {
+            let mut baseline = Baseline::load_or_new(&baseline_options.path)?;
+            baseline.unused_exports = keys.into_iter().collect();
+            baseline.save(&baseline_options.path)?;
+            println!(
+                "Baseline updated with {} unused exports",
+                baseline.unused_exports.len()
+            );
+            return Ok(Vec::new());
+        }
 
+        let baseline = Baseline::load(&baseline_options.path)?;
+        let total = unused_exports.len();
+        (unused_exports, fixed) = apply_baseline(unused_exports, keys, &baseline.unused_exports);
+        baselined = total - unused_exports.len();
+    }
+    let severities = vec![UNUSED_EXPORT_RULE.severity; unused_exports.len()];
+
+    let root = std::env::current_dir()?.canonicalize()?;
+    if let Some(reporter) = get_reporter(options.format, &root) {
+        let findings = unused_exports
+            .iter()
+            .map(|symbol| unused_export_finding(&mut ln_map, symbol, &root))
+            .collect::<Vec<LanternFinding>>();
+        println!("{}", reporter.report(&findings));
+        return Ok(severities);
+    }
+
+    let total = unused_exports.len();
     for symbol in unused_exports {
         let span = symbol.get_span();
         let span_line = ln_map.get_line_number_from_span(symbol.module_id, span);
@@ -39,7 +96,11 @@ pub fn run(entry_points: &Vec<PathBuf>) -> Result<()> {
         println!();
     }
 
+    print_fixed_entries(&fixed);
     println!("Total unused exports found: {}", total);
+    if baselined > 0 {
+        println!("Unused exports in the baseline: {}", baselined);
+    }
 
-    return Ok(());
+    return Ok(severities);
 }
@@ -1,5 +1,5 @@
 pub mod command;
-mod find_unused_exports;
+pub mod find_unused_exports;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use lantern_symbols_map::build_symbols_map;
+    use lantern_testing::load_fixture;
+
+    use crate::commands::baseline::{apply_baseline, export_key, Baseline};
+    use crate::commands::unused_exports::find_unused_exports::find_unused_exports;
+
+    #[test]
+    fn export_keys() {
+        let ep = vec![load_fixture!("unused_simple_named/index.ts")];
+        let root = load_fixture!("unused_simple_named").canonicalize().unwrap();
+        let sm = build_symbols_map(&ep).unwrap();
+        let mut keys = find_unused_exports(&sm)
+            .unwrap()
+            .iter()
+            .map(|symbol| export_key(&sm, symbol, &root))
+            .collect::<Vec<String>>();
+        keys.sort();
+        assert_eq!(keys, vec!["a.ts#B"]);
+    }
+
+    #[test]
+    fn new_and_fixed_findings() {
+        let baseline = ["a.ts#A", "b.ts#B"]
+            .map(String::from)
+            .into_iter()
+            .collect::<BTreeSet<String>>();
+        let keys = ["a.ts#A", "c.ts#C"].map(String::from).to_vec();
+        let (new, fixed) = apply_baseline(vec![1, 2], keys, &baseline);
+        assert_eq!(new, vec![2]);
+        assert_eq!(fixed, vec!["b.ts#B"]);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let path =
+            std::env::temp_dir().join(format!("lantern_baseline_{}.json", std::process::id()));
+        let mut baseline = Baseline::load_or_new(&path).unwrap();
+        baseline.unused_exports.insert("a.ts#A".to_string());
+        baseline
+            .cycles
+            .insert(vec!["a.ts".to_string(), "b.ts".to_string()]);
+        baseline.save(&path).unwrap();
+
+        let loaded = Baseline::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.unused_exports, baseline.unused_exports);
+        assert_eq!(loaded.cycles, baseline.cycles);
+    }
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+    use lantern_formatters::findings_reporter::LanternSeverity;
+
+    use crate::commands::findings::{parse_fail_on, FailOn, FailOptions};
+
+    fn fail_options(fail_on: Option<&str>, max_warnings: Option<usize>) -> FailOptions {
+        return FailOptions {
+            fail_on: fail_on.map(|value| parse_fail_on(value).unwrap()),
+            max_warnings,
+        };
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(parse_fail_on("3"), Ok(FailOn::Count(3)));
+        assert_eq!(
+            parse_fail_on("error"),
+            Ok(FailOn::Severity(LanternSeverity::Error))
+        );
+        assert!(parse_fail_on("critical").is_err());
+    }
+
+    #[test]
+    fn fails_on_any_finding_by_default() {
+        let options = fail_options(None, None);
+        assert!(!options.should_fail(&[]));
+        assert!(options.should_fail(&[LanternSeverity::Note]));
+    }
+
+    #[test]
+    fn fails_on_count_or_severity() {
+        let warnings = vec![LanternSeverity::Warning, LanternSeverity::Warning];
+        assert!(fail_options(Some("1"), None).should_fail(&warnings));
+        assert!(!fail_options(Some("2"), None).should_fail(&warnings));
+        assert!(fail_options(Some("warning"), None).should_fail(&warnings));
+        assert!(!fail_options(Some("error"), None).should_fail(&warnings));
+    }
+
+    #[test]
+    fn count_is_a_limit() {
+        assert!(!fail_options(Some("0"), None).should_fail(&[]));
+        assert!(fail_options(Some("0"), None).should_fail(&[LanternSeverity::Note]));
+        assert!(!fail_options(Some("1"), None).should_fail(&[LanternSeverity::Note]));
+    }
+
+    #[test]
+    fn max_warnings() {
+        let options = fail_options(None, Some(1));
+        assert!(!options.should_fail(&[LanternSeverity::Warning, LanternSeverity::Note]));
+        assert!(options.should_fail(&[LanternSeverity::Warning, LanternSeverity::Warning]));
+        assert!(options.should_fail(&[LanternSeverity::Error]));
+    }
+}
@@ -1,2 +1,6 @@
+mod baseline;
+mod fail_on;
+mod reporters;
+mod rev;
 mod unused_exports;
 mod used_exports;
@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use lantern_formatters::findings_reporter::{LanternFinding, LanternFindingsReporter};
+    use lantern_formatters::github::GitHubReporter;
+    use lantern_formatters::gitlab::GitLabReporter;
+    use lantern_formatters::junit::JUnitReporter;
+    use lantern_formatters::sarif::SarifReporter;
+    use lantern_symbols_map::build_symbols_map;
+    use lantern_testing::load_fixture;
+
+    use crate::commands::findings::unused_export_finding;
+    use crate::commands::unused_exports::find_unused_exports::find_unused_exports;
+
+    fn get_findings() -> (Vec<LanternFinding>, PathBuf) {
+        let ep = vec![load_fixture!("unused_simple_named/index.ts")];
+        let root = load_fixture!("unused_simple_named").canonicalize().unwrap();
+        let mut sm = build_symbols_map(&ep).unwrap();
+        let findings = find_unused_exports(&sm)
+            .unwrap()
+            .iter()
+            .map(|symbol| unused_export_finding(&mut sm, symbol, &root))
+            .collect::<Vec<LanternFinding>>();
+        return (findings, root);
+    }
+
+    #[test]
+    fn sarif_log() {
+        let (findings, root) = get_findings();
+        let log = SarifReporter::new(&root).report(&findings);
+        let log: serde_json::Value = serde_json::from_str(&log).unwrap();
+        let run = &log["runs"][0];
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "unused-export");
+        assert!(run["originalUriBaseIds"]["%SRCROOT%"]["uri"]
+            .as_str()
+            .unwrap()
+            .starts_with("file:///"));
+
+        let result = &run["results"][0];
+        assert_eq!(result["ruleIndex"], 0);
+        assert_eq!(result["level"], "warning");
+        assert_eq!(result["message"]["text"], "unused export: B");
+        assert_eq!(result["partialFingerprints"]["lanternKey/v1"], "a.ts#B");
+
+        let physical_location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(physical_location["artifactLocation"]["uri"], "a.ts");
+        assert_eq!(
+            physical_location["artifactLocation"]["uriBaseId"],
+            "%SRCROOT%"
+        );
+        assert_eq!(physical_location["region"]["startLine"], 3);
+        assert_eq!(physical_location["region"]["startColumn"], 14);
+    }
+
+    #[test]
+    fn github_annotations() {
+        let (findings, _) = get_findings();
+        assert_eq!(
+            GitHubReporter.report(&findings),
+            "::warning file=a.ts,line=3,col=14,endLine=3,endColumn=15,title=unused-export::unused export: B"
+        );
+    }
+
+    #[test]
+    fn gitlab_code_quality() {
+        let (findings, _) = get_findings();
+        let report: serde_json::Value =
+            serde_json::from_str(&GitLabReporter.report(&findings)).unwrap();
+        let issue = &report[0];
+        assert_eq!(issue["check_name"], "unused-export");
+        assert_eq!(issue["fingerprint"], "unused-export:a.ts#B");
+        assert_eq!(issue["severity"], "minor");
+        assert_eq!(issue["location"]["path"], "a.ts");
+        assert_eq!(issue["location"]["positions"]["begin"]["line"], 3);
+    }
+
+    #[test]
+    fn junit_xml() {
+        let (findings, _) = get_findings();
+        let report = JUnitReporter.report(&findings);
+        assert!(report.contains(r#"<testsuites name="lantern" tests="1" failures="1">"#));
+        assert!(report.contains(r#"<testsuite name="unused-export" tests="1" failures="1">"#));
+        assert!(
+            report.contains(r#"<testcase name="a.ts#B" classname="a.ts" file="a.ts" line="3">"#)
+        );
+        assert!(report.contains(
+            r#"<failure type="unused-export" message="unused export: B">a.ts:3:14: unused export: B</failure>"#
+        ));
+    }
+}
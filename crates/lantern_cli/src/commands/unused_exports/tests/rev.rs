@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use git2::{Repository, Signature};
+
+    use crate::commands::source::SourceOptions;
+    use crate::commands::unused_exports::find_unused_exports::find_unused_exports;
+
+    fn init_repo(name: &str) -> (Repository, PathBuf) {
+        let dir = std::env::temp_dir().join(format!("lantern_rev_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let dir = dir.canonicalize().unwrap();
+        let repo = Repository::init(&dir).unwrap();
+        return (repo, dir);
+    }
+
+    fn commit_all(repo: &Repository) {
+        let mut index = repo.index().unwrap();
+        index
+            .add_all(["*.ts"].iter(), git2::IndexAddOption::DEFAULT, None)
+            .unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = Signature::now("lantern", "lantern@example.com").unwrap();
+        repo.commit(Some("HEAD"), &signature, &signature, "initial", &tree, &[])
+            .unwrap();
+    }
+
+    fn write(dir: &Path, name: &str, content: &str) {
+        let path = dir.join(name);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn unused_export_names(entry_point: &Path, rev: Option<&str>) -> Vec<String> {
+        let source = SourceOptions {
+            rev: rev.map(String::from),
+        };
+        let sm = source
+            .build_symbols_map(&[entry_point.to_path_buf()])
+            .unwrap();
+        return find_unused_exports(&sm)
+            .unwrap()
+            .iter()
+            .map(|symbol| symbol.get_name().unwrap().to_string())
+            .collect();
+    }
+
+    #[test]
+    fn reads_files_from_revision() {
+        let (repo, dir) = init_repo("unused_exports");
+        write(
+            &dir,
+            "index.ts",
+            "import { A } from \"./a\";\nimport dep from \"dep\";\n",
+        );
+        write(&dir, "a.ts", "export const A = 1;\nexport const B = 2;\n");
+        commit_all(&repo);
+        // Uncommitted changes and untracked packages.
+        write(&dir, "a.ts", "export const A = 1;\n");
+        write(&dir, "node_modules/dep/index.js", "export default 1;\n");
+
+        let entry_point = dir.join("index.ts");
+        assert_eq!(
+            unused_export_names(&entry_point, Some("HEAD")),
+            vec!["B".to_string()]
+        );
+        assert!(unused_export_names(&entry_point, None).is_empty());
+
+        let source = SourceOptions {
+            rev: Some("HEAD".to_string()),
+        };
+        let sm = source.build_symbols_map(&[entry_point]).unwrap();
+        assert!(sm.unresolved_imports.is_empty());
+    }
+}
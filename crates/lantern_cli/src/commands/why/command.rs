@@ -0,0 +1,45 @@
+use std::path::PathBuf;
+
+use color_eyre::eyre::Result;
+
+use lantern_dependency_graph::LanternFileDependencyMap;
+
+use crate::commands::edge_annotation::{annotate_edge, display_path};
+use crate::commands::source::SourceOptions;
+use crate::commands::why::find_paths::{find_import_paths, get_target_modules};
+
+pub fn run(
+    entry_points: &[PathBuf],
+    target: &str,
+    max_paths: usize,
+    source: &SourceOptions,
+) -> Result<()> {
+    let ln_map = source.build_symbols_map(entry_points)?;
+    let mut depgraph = LanternFileDependencyMap::new(ln_map);
+    depgraph.build_dependency_graph();
+
+    let targets = get_target_modules(&depgraph, target)?;
+    let paths = find_import_paths(&depgraph, &targets, max_paths);
+
+    for (idx, path) in paths.iter().enumerate() {
+        let hops = path
+            .iter()
+            .map(|module_id| display_path(depgraph.symbols_map.get_module_path(*module_id)))
+            .collect::<Vec<String>>();
+        println!("Path {}: {}", idx + 1, hops.join(" → "));
+        println!();
+
+        for pos in 1..path.len() {
+            if let Some(edge) = annotate_edge(&mut depgraph, path[pos - 1], path[pos]) {
+                println!("{}", edge.annotation.print());
+                println!();
+            }
+        }
+    }
+
+    if paths.is_empty() {
+        println!("{} is not reachable from any entry", target);
+    }
+
+    return Ok(());
+}
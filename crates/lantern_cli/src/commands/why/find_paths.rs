@@ -0,0 +1,59 @@
+use std::collections::HashSet;
+
+use color_eyre::eyre::{eyre, Result};
+
+use lantern_dependency_graph::algorithms::shortest_paths::shortest_paths;
+use lantern_dependency_graph::graph::PackageNames;
+use lantern_dependency_graph::LanternFileDependencyMap;
+
+/// Modules the target refers to, either a single file or every file of a package.
+pub fn get_target_modules(
+    depgraph: &LanternFileDependencyMap,
+    target: &str,
+) -> Result<HashSet<usize>> {
+    let ln_map = &depgraph.symbols_map;
+    let fs = ln_map.get_file_system();
+    let target_path = std::env::current_dir()?.join(target);
+    if fs.metadata(&target_path).is_ok() {
+        let target_path = fs.canonicalize(&target_path)?;
+        return match ln_map.get_module_id(target_path.to_str().unwrap()) {
+            Some(module_id) => Ok(HashSet::from([module_id])),
+            None => Err(eyre!("{} is not imported from any entry", target)),
+        };
+    }
+
+    let mut packages = PackageNames::new(fs.clone());
+    let modules = ln_map
+        .modules
+        .iter()
+        .enumerate()
+        .filter(|(_, module)| {
+            packages.get_package_name(&module.file_path).as_deref() == Some(target)
+        })
+        .map(|(module_id, _)| module_id)
+        .collect::<HashSet<usize>>();
+    if modules.is_empty() {
+        return Err(eyre!(
+            "No file or package {:?} in the dependency graph",
+            target
+        ));
+    }
+    return Ok(modules);
+}
+
+/// Up to `limit` distinct import paths from any entry to the target, shortest first.
+pub fn find_import_paths(
+    depgraph: &LanternFileDependencyMap,
+    targets: &HashSet<usize>,
+    limit: usize,
+) -> Vec<Vec<usize>> {
+    let entries = depgraph
+        .symbols_map
+        .modules
+        .iter()
+        .enumerate()
+        .filter(|(_, module)| module.is_entry)
+        .map(|(module_id, _)| module_id)
+        .collect::<Vec<usize>>();
+    return shortest_paths(&entries, targets, &depgraph.dependency_map, limit);
+}
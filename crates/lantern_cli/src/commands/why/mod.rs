@@ -0,0 +1,5 @@
+pub mod command;
+mod find_paths;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1 @@
+mod why;
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use lantern_testing::load_fixture;
+
+    use crate::commands::test_utils::{build_depgraph, fixture_root};
+    use crate::commands::why::find_paths::{find_import_paths, get_target_modules};
+
+    fn find_paths(target: &str, limit: usize) -> Vec<Vec<String>> {
+        let depgraph = build_depgraph("why_paths", &["index.ts"]);
+        let targets = get_target_modules(&depgraph, target).unwrap();
+        let root = fixture_root("why_paths");
+        return find_import_paths(&depgraph, &targets, limit)
+            .iter()
+            .map(|path| {
+                return path
+                    .iter()
+                    .map(|id| {
+                        let path = depgraph.symbols_map.get_module_path(*id);
+                        path.strip_prefix(&root).unwrap().display().to_string()
+                    })
+                    .collect();
+            })
+            .collect();
+    }
+
+    #[test]
+    fn shortest_path_to_file() {
+        let target = load_fixture!("why_paths/heavy.ts");
+        assert_eq!(
+            find_paths(target.to_str().unwrap(), 1),
+            vec![vec!["index.ts", "a.ts", "heavy.ts"]]
+        );
+    }
+
+    #[test]
+    fn all_paths_to_file() {
+        let target = load_fixture!("why_paths/heavy.ts");
+        assert_eq!(
+            find_paths(target.to_str().unwrap(), 10),
+            vec![
+                vec!["index.ts", "a.ts", "heavy.ts"],
+                vec!["index.ts", "b.ts", "c.ts", "heavy.ts"]
+            ]
+        );
+    }
+
+    #[test]
+    fn path_to_package() {
+        assert_eq!(
+            find_paths("@acme/charts", 10),
+            vec![vec!["index.ts", "b.ts", "c.ts", "packages/charts/index.ts"]]
+        );
+    }
+
+    #[test]
+    fn unknown_target() {
+        let depgraph = build_depgraph("why_paths", &["index.ts"]);
+        assert!(get_target_modules(&depgraph, "does-not-exist").is_err());
+    }
+}
@@ -2,9 +2,27 @@ use std::path::PathBuf;
 
 use clap::{command, Parser, Subcommand};
 use color_eyre::eyre::Result;
+use lantern_formatters::findings_reporter::LanternSeverity;
 
 mod commands;
 
+use commands::affected::command::{AffectedFormat, AffectedOptions, DEFAULT_TEST_PATTERNS};
+use commands::baseline::get_baseline_options;
+use commands::cycles::command::CyclesOptions;
+use commands::depgraph::command::{DepgraphFormat, DepgraphOptions};
+use commands::depgraph::focus::FocusDirection;
+use commands::diff::command::{get_diff_side, DiffFormat, DiffOptions};
+use commands::dump::command::{DumpFormat, DumpOptions};
+use commands::export_db::command::ExportDbOptions;
+use commands::files_with_reexports::FilesWithReExportsOptions;
+use commands::findings::{FailOptions, ReportFormat};
+use commands::grouping::GroupBy;
+use commands::metrics::command::{MetricsFormat, MetricsOptions};
+use commands::metrics::compute_metrics::MetricsSort;
+use commands::size::command::{SizeFormat, SizeOptions};
+use commands::source::SourceOptions;
+use commands::unused_exports::command::UnusedExportsOptions;
+
 #[derive(Parser)]
 #[command(version, about, long_about = None)]
 #[command(propagate_version = true)]
@@ -19,58 +37,609 @@ enum Commands {
     UnusedExports {
         #[arg(required = true)]
         path: Vec<PathBuf>,
+
+        #[arg(long, value_enum, default_value_t = ReportFormat::Text)]
+        format: ReportFormat,
+
+        /// Only report unused exports missing from this baseline file
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+
+        /// Record the current unused exports in the baseline file
+        #[arg(long, requires = "baseline")]
+        update_baseline: bool,
+
+        #[command(flatten)]
+        source: SourceOptions,
+
+        #[command(flatten)]
+        fail: FailOptions,
     },
 
     /// Find files with re-exports
     FilesWithReExports {
         #[arg(required = true)]
         path: Vec<PathBuf>,
+
+        #[arg(long, value_enum, default_value_t = ReportFormat::Text)]
+        format: ReportFormat,
+
+        #[command(flatten)]
+        source: SourceOptions,
+
+        #[command(flatten)]
+        fail: FailOptions,
     },
 
     /// Build a dependency graph for a project
     Depgraph {
         #[arg(required = true)]
         path: Vec<PathBuf>,
+
+        #[arg(long, value_enum, default_value_t = DepgraphFormat::Graphviz)]
+        format: DepgraphFormat,
+
+        /// Directory paths in the output are relative to, defaults to the current directory
+        #[arg(long)]
+        root: Option<PathBuf>,
+
+        /// Collapse files into directory or package nodes
+        #[arg(long, value_enum)]
+        group_by: Option<GroupBy>,
+
+        /// Number of leading directories to keep with --group-by dir
+        #[arg(long, requires = "group_by")]
+        depth: Option<usize>,
+
+        /// Only output files around this file
+        #[arg(long)]
+        focus: Option<PathBuf>,
+
+        /// Number of imports to follow from --focus
+        #[arg(long, requires = "focus")]
+        focus_depth: Option<usize>,
+
+        /// Which imports to follow from --focus
+        #[arg(long, value_enum, default_value_t = FocusDirection::Both, requires = "focus")]
+        direction: FocusDirection,
+
+        /// Leave files matching this glob out of the graph, can be repeated
+        #[arg(long)]
+        exclude: Vec<String>,
+
+        /// Write the graph to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        #[command(flatten)]
+        source: SourceOptions,
     },
 
     /// Find all file level cycles
     Cycles {
         #[arg(required = true)]
         path: Vec<PathBuf>,
+
+        #[arg(long, value_enum, default_value_t = ReportFormat::Text, conflicts_with_all = ["suggest", "group_by"])]
+        format: ReportFormat,
+
+        /// Suggest the smallest set of imports to remove to make the graph acyclic
+        #[arg(long, conflicts_with = "group_by")]
+        suggest: bool,
+
+        /// Find cycles between directories or packages instead of files
+        #[arg(long, value_enum)]
+        group_by: Option<GroupBy>,
+
+        /// Number of leading directories to keep with --group-by dir
+        #[arg(long, requires = "group_by")]
+        depth: Option<usize>,
+
+        /// Directory group names are relative to, defaults to the current directory
+        #[arg(long)]
+        root: Option<PathBuf>,
+
+        /// Only report cycles missing from this baseline file
+        #[arg(long, conflicts_with_all = ["suggest", "group_by"])]
+        baseline: Option<PathBuf>,
+
+        /// Record the current cycles in the baseline file
+        #[arg(long, requires = "baseline")]
+        update_baseline: bool,
+
+        #[command(flatten)]
+        source: SourceOptions,
+
+        #[command(flatten)]
+        fail: FailOptions,
+    },
+
+    /// Explain how entries reach a file or package
+    Why {
+        #[arg(required = true)]
+        path: Vec<PathBuf>,
+
+        /// File or package name to explain
+        #[arg(short, long)]
+        target: String,
+
+        /// List up to this many distinct import paths, shortest first
+        #[arg(long, default_value_t = 1)]
+        max_paths: usize,
+
+        #[command(flatten)]
+        source: SourceOptions,
+    },
+
+    /// Check imports against architecture boundary rules
+    CheckBoundaries {
+        #[arg(required = true)]
+        path: Vec<PathBuf>,
+
+        /// JSON file with boundary rules
+        #[arg(short, long, default_value = "lantern-boundaries.json")]
+        config: PathBuf,
+
+        /// Directory rule globs are relative to, defaults to the config file's directory
+        #[arg(long)]
+        root: Option<PathBuf>,
+
+        #[command(flatten)]
+        source: SourceOptions,
+
+        #[command(flatten)]
+        fail: FailOptions,
+    },
+
+    /// Report coupling metrics per file, directory or package
+    Metrics {
+        #[arg(required = true)]
+        path: Vec<PathBuf>,
+
+        /// Report metrics per directory or package instead of per file
+        #[arg(long, value_enum)]
+        group_by: Option<GroupBy>,
+
+        /// Number of leading directories to keep with --group-by dir
+        #[arg(long, requires = "group_by")]
+        depth: Option<usize>,
+
+        /// Column to sort by, numbers are sorted in descending order
+        #[arg(long, value_enum, default_value_t = MetricsSort::Path)]
+        sort: MetricsSort,
+
+        /// Only output this many rows
+        #[arg(long)]
+        limit: Option<usize>,
+
+        #[arg(long, value_enum, default_value_t = MetricsFormat::Table)]
+        format: MetricsFormat,
+
+        /// Directory paths are relative to, defaults to the current directory
+        #[arg(long)]
+        root: Option<PathBuf>,
+
+        #[command(flatten)]
+        source: SourceOptions,
+    },
+
+    /// Estimate the transitive source size of each entry
+    Size {
+        #[arg(required = true)]
+        path: Vec<PathBuf>,
+
+        /// Number of largest modules and retained sizes to show per entry
+        #[arg(long, default_value_t = 10)]
+        top: usize,
+
+        /// Split the total into first-party and node_modules sources
+        #[arg(long)]
+        split: bool,
+
+        #[arg(long, value_enum, default_value_t = SizeFormat::Text)]
+        format: SizeFormat,
+
+        #[command(flatten)]
+        source: SourceOptions,
+    },
+
+    /// Compare modules, imports, cycles, unused exports and entry exports between two
+    /// snapshots or git revisions
+    Diff {
+        /// Entry points, only needed to analyse revisions or the working tree
+        path: Vec<PathBuf>,
+
+        /// Git revision to compare from
+        #[arg(long, required_unless_present = "base_snapshot")]
+        base: Option<String>,
+
+        /// Snapshot written by --write-snapshot or dump to compare from
+        #[arg(long, conflicts_with = "base")]
+        base_snapshot: Option<PathBuf>,
+
+        /// Git revision to compare to, defaults to the working tree
+        #[arg(long)]
+        head: Option<String>,
+
+        /// Snapshot written by --write-snapshot or dump to compare to
+        #[arg(long, conflicts_with = "head")]
+        head_snapshot: Option<PathBuf>,
+
+        /// Directory snapshot paths are relative to, defaults to the current directory
+        #[arg(long)]
+        root: Option<PathBuf>,
+
+        /// Write a snapshot of --head to use as --base later
+        #[arg(long)]
+        write_snapshot: Option<PathBuf>,
+
+        #[arg(long, value_enum, default_value_t = DiffFormat::Text)]
+        format: DiffFormat,
+    },
+
+    /// Write the symbols map and dependency graph as versioned JSON or binary, to load
+    /// them later without parsing the project again
+    Dump {
+        #[arg(required = true)]
+        path: Vec<PathBuf>,
+
+        /// Directory module paths are relative to, defaults to the current directory
+        #[arg(long)]
+        root: Option<PathBuf>,
+
+        /// File to write the dump to, defaults to stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        #[arg(long, value_enum, default_value_t = DumpFormat::Json)]
+        format: DumpFormat,
+
+        #[command(flatten)]
+        source: SourceOptions,
+    },
+
+    /// Export modules, symbols, imports, unresolved imports and findings to a SQLite
+    /// database for ad-hoc queries
+    ExportDb {
+        /// Database file to write, replaced if it exists
+        output: PathBuf,
+
+        #[arg(required = true)]
+        path: Vec<PathBuf>,
+
+        /// Directory module and finding paths are relative to, defaults to the current
+        /// directory
+        #[arg(long)]
+        root: Option<PathBuf>,
+
+        #[command(flatten)]
+        source: SourceOptions,
+    },
+
+    /// Run a language server publishing diagnostics for the project over stdio
+    Lsp {
+        #[arg(required = true)]
+        path: Vec<PathBuf>,
     },
 
     /// Find affected files in a project
     Affected {
-        #[arg(short, long, required = true)]
+        #[arg(short, long, required_unless_present = "tests")]
         entries: Vec<PathBuf>,
 
-        #[arg(short, long, required = true)]
+        #[arg(short, long, required_unless_present_any = ["since", "diff"])]
         changed: Vec<PathBuf>,
+
+        /// Also treat files changed since the merge base with this git revision as changed
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Also treat files and lines changed in this unified diff file as changed
+        #[arg(long)]
+        diff: Option<PathBuf>,
+
+        /// Only follow imports of exports touched by the changed lines
+        #[arg(long)]
+        symbols: bool,
+
+        /// Snapshot or dump taken before the change, used for deleted and renamed files
+        #[arg(long)]
+        base_snapshot: Option<PathBuf>,
+
+        /// Write a snapshot of the current graph to use as --base-snapshot later
+        #[arg(long)]
+        write_snapshot: Option<PathBuf>,
+
+        /// Directory snapshot paths are relative to, defaults to the current directory
+        #[arg(long)]
+        root: Option<PathBuf>,
+
+        /// Report every transitive dependent instead of entries only
+        #[arg(long)]
+        all_dependents: bool,
+
+        /// Report test files depending on the changed files, e.g. to pass to jest or vitest
+        /// with `xargs`
+        #[arg(long)]
+        tests: bool,
+
+        /// Glob matching test files relative to --root, can be repeated
+        #[arg(long = "test-pattern", default_values_t = DEFAULT_TEST_PATTERNS.map(String::from))]
+        test_patterns: Vec<String>,
+
+        #[arg(long, value_enum, default_value_t = AffectedFormat::Lines)]
+        format: AffectedFormat,
+
+        #[command(flatten)]
+        source: SourceOptions,
     },
 }
 
-fn main() -> Result<()> {
-    color_eyre::install()?;
+const EXIT_OK: i32 = 0;
+const EXIT_FINDINGS: i32 = 1;
+const EXIT_ERROR: i32 = 2;
+
+fn main() {
+    color_eyre::install().unwrap();
     let cli = CLI::parse();
 
-    match &cli.command {
-        Commands::UnusedExports { path } => {
-            commands::unused_exports::command::run(path).unwrap();
+    // Panics are reported by the color_eyre hook, they only need the right exit code here.
+    let code = match std::panic::catch_unwind(|| run(&cli.command)) {
+        Ok(Ok(code)) => code,
+        Ok(Err(err)) => {
+            eprintln!("Error: {:?}", err);
+            EXIT_ERROR
         }
-        Commands::FilesWithReExports { path } => {
-            commands::files_with_reexports::run(path).unwrap();
+        Err(_) => EXIT_ERROR,
+    };
+    std::process::exit(code);
+}
+
+fn run(command: &Commands) -> Result<i32> {
+    match command {
+        Commands::UnusedExports {
+            path,
+            format,
+            baseline,
+            update_baseline,
+            source,
+            fail,
+        } => {
+            let severities = commands::unused_exports::command::run(
+                path,
+                &UnusedExportsOptions {
+                    format: *format,
+                    baseline: get_baseline_options(baseline, *update_baseline),
+                    source: source.clone(),
+                },
+            )?;
+            return Ok(get_exit_code(fail, &severities));
+        }
+        Commands::FilesWithReExports {
+            path,
+            format,
+            source,
+            fail,
+        } => {
+            let severities = commands::files_with_reexports::run(
+                path,
+                &FilesWithReExportsOptions {
+                    format: *format,
+                    source: source.clone(),
+                },
+            )?;
+            return Ok(get_exit_code(fail, &severities));
+        }
+        Commands::Depgraph {
+            path,
+            format,
+            root,
+            group_by,
+            depth,
+            focus,
+            focus_depth,
+            direction,
+            exclude,
+            output,
+            source,
+        } => {
+            commands::depgraph::command::build(
+                path,
+                &DepgraphOptions {
+                    format: *format,
+                    root: root.clone(),
+                    group_by: *group_by,
+                    depth: *depth,
+                    focus: focus.clone(),
+                    focus_depth: *focus_depth,
+                    direction: *direction,
+                    exclude: exclude.clone(),
+                    output: output.clone(),
+                    source: source.clone(),
+                },
+            )?;
+        }
+        Commands::Cycles {
+            path,
+            format,
+            suggest,
+            group_by,
+            depth,
+            root,
+            baseline,
+            update_baseline,
+            source,
+            fail,
+        } => {
+            let severities = commands::cycles::command::run(
+                path,
+                &CyclesOptions {
+                    format: *format,
+                    suggest: *suggest,
+                    group_by: *group_by,
+                    depth: *depth,
+                    root: root.clone(),
+                    baseline: get_baseline_options(baseline, *update_baseline),
+                    source: source.clone(),
+                },
+            )?;
+            return Ok(get_exit_code(fail, &severities));
+        }
+        Commands::Why {
+            path,
+            target,
+            max_paths,
+            source,
+        } => {
+            commands::why::command::run(path, target, *max_paths, source)?;
         }
-        Commands::Depgraph { path } => {
-            commands::depgraph::build(path).unwrap();
+        Commands::CheckBoundaries {
+            path,
+            config,
+            root,
+            source,
+            fail,
+        } => {
+            let severities = commands::check_boundaries::command::run(path, config, root, source)?;
+            return Ok(get_exit_code(fail, &severities));
         }
-        Commands::Cycles { path } => {
-            commands::cycles::run(path).unwrap();
+        Commands::Metrics {
+            path,
+            group_by,
+            depth,
+            sort,
+            limit,
+            format,
+            root,
+            source,
+        } => {
+            commands::metrics::command::run(
+                path,
+                &MetricsOptions {
+                    group_by: *group_by,
+                    depth: *depth,
+                    sort: *sort,
+                    limit: *limit,
+                    format: *format,
+                    root: root.clone(),
+                    source: source.clone(),
+                },
+            )?;
         }
-        Commands::Affected { entries, changed } => {
-            println!("Entries: {:?}, Changed: {:?}", entries, changed);
-            commands::affected::run(entries, changed).unwrap();
+        Commands::Size {
+            path,
+            top,
+            split,
+            format,
+            source,
+        } => {
+            commands::size::command::run(
+                path,
+                &SizeOptions {
+                    top: *top,
+                    split: *split,
+                    format: *format,
+                    source: source.clone(),
+                },
+            )?;
+        }
+        Commands::Diff {
+            path,
+            base,
+            base_snapshot,
+            head,
+            head_snapshot,
+            root,
+            write_snapshot,
+            format,
+        } => {
+            commands::diff::command::run(
+                path,
+                &DiffOptions {
+                    base: get_diff_side(base, base_snapshot),
+                    head: get_diff_side(head, head_snapshot),
+                    root: root.clone(),
+                    write_snapshot: write_snapshot.clone(),
+                    format: *format,
+                },
+            )?;
+        }
+        Commands::Dump {
+            path,
+            root,
+            output,
+            format,
+            source,
+        } => {
+            commands::dump::command::run(
+                path,
+                &DumpOptions {
+                    root: root.clone(),
+                    output: output.clone(),
+                    format: *format,
+                    source: source.clone(),
+                },
+            )?;
+        }
+        Commands::ExportDb {
+            output,
+            path,
+            root,
+            source,
+        } => {
+            commands::export_db::command::run(
+                output,
+                path,
+                &ExportDbOptions {
+                    root: root.clone(),
+                    source: source.clone(),
+                },
+            )?;
+        }
+        Commands::Lsp { path } => {
+            commands::lsp::command::run(path)?;
+        }
+        Commands::Affected {
+            entries,
+            changed,
+            since,
+            diff,
+            symbols,
+            base_snapshot,
+            write_snapshot,
+            root,
+            all_dependents,
+            tests,
+            test_patterns,
+            format,
+            source,
+        } => {
+            commands::affected::command::run(
+                entries,
+                changed,
+                &AffectedOptions {
+                    since: since.clone(),
+                    diff: diff.clone(),
+                    symbols: *symbols,
+                    base_snapshot: base_snapshot.clone(),
+                    write_snapshot: write_snapshot.clone(),
+                    root: root.clone(),
+                    all_dependents: *all_dependents,
+                    tests: *tests,
+                    test_patterns: test_patterns.clone(),
+                    format: *format,
+                    source: source.clone(),
+                },
+            )?;
         }
     };
 
-    return Ok(());
+    return Ok(EXIT_OK);
+}
+
+fn get_exit_code(fail: &FailOptions, severities: &[LanternSeverity]) -> i32 {
+    if fail.should_fail(severities) {
+        return EXIT_FINDINGS;
+    }
+    return EXIT_OK;
 }
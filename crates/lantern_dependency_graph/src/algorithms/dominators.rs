@@ -0,0 +1,139 @@
+use std::collections::{HashMap, HashSet};
+
+/// Immediate dominators of the nodes reachable from `root`, using the iterative algorithm
+/// by Cooper, Harvey and Kennedy. Returns (node, immediate dominator) pairs in reverse
+/// postorder, so every node comes after its dominator. The root has no dominator.
+pub fn immediate_dominators(
+    root: usize,
+    graph: &HashMap<usize, HashSet<usize>>,
+) -> Vec<(usize, Option<usize>)> {
+    let order = get_reverse_postorder(root, graph);
+    let index: HashMap<usize, usize> = order.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+
+    let mut predecessors: HashMap<usize, Vec<usize>> = HashMap::new();
+    for from in &order {
+        for to in graph.get(from).unwrap_or(&HashSet::new()) {
+            predecessors.entry(*to).or_default().push(*from);
+        }
+    }
+
+    let mut idom: HashMap<usize, usize> = HashMap::from([(root, root)]);
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for node in order.iter().skip(1) {
+            let mut new_idom: Option<usize> = None;
+            for pred in predecessors.get(node).unwrap_or(&Vec::new()) {
+                if !idom.contains_key(pred) {
+                    continue;
+                }
+                new_idom = match new_idom {
+                    None => Some(*pred),
+                    Some(cur) => Some(intersect(&idom, &index, *pred, cur)),
+                };
+            }
+            let new_idom = new_idom.unwrap();
+            if idom.get(node) != Some(&new_idom) {
+                idom.insert(*node, new_idom);
+                changed = true;
+            }
+        }
+    }
+
+    return order
+        .iter()
+        .map(|node| (*node, (*node != root).then(|| idom[node])))
+        .collect();
+}
+
+fn intersect(
+    idom: &HashMap<usize, usize>,
+    index: &HashMap<usize, usize>,
+    a: usize,
+    b: usize,
+) -> usize {
+    let (mut a, mut b) = (a, b);
+    while a != b {
+        while index[&a] > index[&b] {
+            a = idom[&a];
+        }
+        while index[&b] > index[&a] {
+            b = idom[&b];
+        }
+    }
+    return a;
+}
+
+fn get_reverse_postorder(root: usize, graph: &HashMap<usize, HashSet<usize>>) -> Vec<usize> {
+    let mut visited = HashSet::from([root]);
+    let mut postorder = Vec::new();
+    // Iterative DFS, each frame holds a node and its remaining successors.
+    let mut stack = vec![(root, get_sorted_successors(root, graph))];
+    while let Some((node, successors)) = stack.last_mut() {
+        if let Some(next) = successors.pop() {
+            if visited.insert(next) {
+                stack.push((next, get_sorted_successors(next, graph)));
+            }
+        } else {
+            postorder.push(*node);
+            stack.pop();
+        }
+    }
+    postorder.reverse();
+    return postorder;
+}
+
+// Reversed, so popping yields successors in ascending order.
+fn get_sorted_successors(node: usize, graph: &HashMap<usize, HashSet<usize>>) -> Vec<usize> {
+    let mut successors = graph
+        .get(&node)
+        .unwrap_or(&HashSet::new())
+        .iter()
+        .copied()
+        .collect::<Vec<usize>>();
+    successors.sort_by(|a, b| b.cmp(a));
+    return successors;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_graph(edges: &[(usize, usize)]) -> HashMap<usize, HashSet<usize>> {
+        let mut graph: HashMap<usize, HashSet<usize>> = HashMap::new();
+        for (from, to) in edges {
+            graph.entry(*from).or_default().insert(*to);
+        }
+        return graph;
+    }
+
+    #[test]
+    fn diamond_and_chain() {
+        // 0 -> 1 -> 3, 0 -> 2 -> 3, 3 -> 4, 5 is unreachable.
+        let graph = build_graph(&[(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (5, 0)]);
+        let mut idoms = immediate_dominators(0, &graph);
+        assert_eq!(idoms[0], (0, None));
+        idoms.sort();
+        assert_eq!(
+            idoms,
+            vec![
+                (0, None),
+                (1, Some(0)),
+                (2, Some(0)),
+                (3, Some(0)),
+                (4, Some(3))
+            ]
+        );
+    }
+
+    #[test]
+    fn cycles() {
+        let graph = build_graph(&[(0, 1), (1, 2), (2, 1), (2, 3)]);
+        let mut idoms = immediate_dominators(0, &graph);
+        idoms.sort();
+        assert_eq!(
+            idoms,
+            vec![(0, None), (1, Some(0)), (2, Some(1)), (3, Some(2))]
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::algorithms::strongly_connected_components::cyclic_components;
+
+/// Cycles that visit every node at most once.
+#[derive(Debug)]
+pub struct ElementaryCycles {
+    pub cycles: Vec<Vec<usize>>,
+    /// The search stopped at the limit, `cycles` is only part of the cycles.
+    pub is_truncated: bool,
+}
+
+/// Johnson's algorithm, run on every cyclic component. Each cycle starts at its lowest
+/// node and the search stops once `limit` cycles are found, since dense components
+/// can have exponentially many.
+pub fn elementary_cycles(
+    nodes: usize,
+    graph: &HashMap<usize, HashSet<usize>>,
+    limit: usize,
+) -> ElementaryCycles {
+    struct State<'a> {
+        graph: &'a HashMap<usize, HashSet<usize>>,
+        start: usize,
+        members: HashSet<usize>,
+        limit: usize,
+        stack: Vec<usize>,
+        blocked: HashSet<usize>,
+        blocked_by: HashMap<usize, HashSet<usize>>,
+        cycles: Vec<Vec<usize>>,
+        is_truncated: bool,
+    }
+
+    fn unblock(state: &mut State, node: usize) {
+        state.blocked.remove(&node);
+        let waiting = state.blocked_by.remove(&node).unwrap_or_default();
+        for other in waiting {
+            if state.blocked.contains(&other) {
+                unblock(state, other);
+            }
+        }
+    }
+
+    fn circuit(state: &mut State, node: usize) -> bool {
+        let mut found = false;
+        state.stack.push(node);
+        state.blocked.insert(node);
+
+        let mut successors = state
+            .graph
+            .get(&node)
+            .unwrap_or(&HashSet::new())
+            .iter()
+            .copied()
+            .filter(|to| state.members.contains(to))
+            .collect::<Vec<usize>>();
+        successors.sort();
+
+        for to in &successors {
+            if state.cycles.len() >= state.limit {
+                state.is_truncated = true;
+                break;
+            }
+            if *to == state.start {
+                state.cycles.push(state.stack.clone());
+                found = true;
+            } else if !state.blocked.contains(to) && circuit(state, *to) {
+                found = true;
+            }
+        }
+
+        if found {
+            unblock(state, node);
+        } else {
+            for to in successors {
+                state.blocked_by.entry(to).or_default().insert(node);
+            }
+        }
+
+        state.stack.pop();
+        return found;
+    }
+
+    let mut state = State {
+        graph,
+        start: 0,
+        members: HashSet::new(),
+        limit,
+        stack: Vec::new(),
+        blocked: HashSet::new(),
+        blocked_by: HashMap::new(),
+        cycles: Vec::new(),
+        is_truncated: false,
+    };
+
+    for component in cyclic_components(nodes, graph) {
+        // Cycles through a node are found once, after which it's left out of the
+        // component for the remaining start nodes.
+        for (pos, start) in component.iter().enumerate() {
+            if state.is_truncated {
+                break;
+            }
+            state.start = *start;
+            state.members = component[pos..].iter().copied().collect();
+            state.blocked.clear();
+            state.blocked_by.clear();
+            circuit(&mut state, *start);
+        }
+    }
+
+    return ElementaryCycles {
+        cycles: state.cycles,
+        is_truncated: state.is_truncated,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use super::*;
+
+    fn graph(edges: &[(usize, usize)]) -> HashMap<usize, HashSet<usize>> {
+        let mut graph: HashMap<usize, HashSet<usize>> = HashMap::new();
+        for (from, to) in edges {
+            graph.entry(*from).or_default().insert(*to);
+        }
+        return graph;
+    }
+
+    #[test]
+    fn finds_every_cycle() {
+        let g = graph(&[(0, 1), (1, 0), (1, 2), (2, 0), (2, 1), (3, 3), (3, 4)]);
+        let result = elementary_cycles(5, &g, 100);
+        assert!(!result.is_truncated);
+        assert_eq!(
+            result.cycles,
+            vec![vec![0, 1], vec![0, 1, 2], vec![1, 2], vec![3]]
+        );
+    }
+
+    #[test]
+    fn stops_at_limit() {
+        let g = graph(&[(0, 1), (1, 0), (1, 2), (2, 1), (2, 0), (0, 2)]);
+        let result = elementary_cycles(3, &g, 2);
+        assert!(result.is_truncated);
+        assert_eq!(result.cycles.len(), 2);
+    }
+}
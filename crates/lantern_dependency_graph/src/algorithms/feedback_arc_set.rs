@@ -0,0 +1,213 @@
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+use crate::algorithms::strongly_connected_components::strongly_connected_components;
+
+/// Approximates the minimum weight feedback arc set of a graph: a set of edges whose
+/// removal makes the graph acyclic.
+///
+/// Every strongly connected component is ordered with the Eades–Lin–Smyth greedy
+/// heuristic, where edges pointing backwards in that order form the initial set. Edges
+/// are then put back, heaviest first, whenever doing so doesn't close a cycle, so the
+/// returned set is minimal even though it's not guaranteed to be minimum.
+pub fn feedback_arc_set<F>(
+    nodes: usize,
+    graph: &HashMap<usize, HashSet<usize>>,
+    weight: F,
+) -> Vec<(usize, usize)>
+where
+    F: Fn(usize, usize) -> usize,
+{
+    let mut feedback_arcs = Vec::new();
+
+    for component in strongly_connected_components(nodes, graph) {
+        let members: HashSet<usize> = component.iter().copied().collect();
+        let mut edges = Vec::new();
+        for from in &component {
+            let mut successors = graph
+                .get(from)
+                .unwrap_or(&HashSet::new())
+                .iter()
+                .filter(|to| members.contains(to))
+                .copied()
+                .collect::<Vec<usize>>();
+            successors.sort();
+            for to in successors {
+                edges.push((*from, to, weight(*from, to).max(1)));
+            }
+        }
+
+        if edges.is_empty() {
+            continue;
+        }
+
+        let order = eades_ordering(&component, &edges);
+        let position: HashMap<usize, usize> = order
+            .iter()
+            .enumerate()
+            .map(|(pos, node)| (*node, pos))
+            .collect();
+
+        let mut acyclic: HashMap<usize, HashSet<usize>> = HashMap::new();
+        let mut backward = Vec::new();
+        for (from, to, w) in edges {
+            if position[&to] <= position[&from] {
+                backward.push((from, to, w));
+            } else {
+                acyclic.entry(from).or_default().insert(to);
+            }
+        }
+
+        backward.sort_by(|a, b| b.2.cmp(&a.2).then(a.0.cmp(&b.0)).then(a.1.cmp(&b.1)));
+        for (from, to, _) in backward {
+            if from != to && !has_path(&acyclic, to, from) {
+                acyclic.entry(from).or_default().insert(to);
+            } else {
+                feedback_arcs.push((from, to));
+            }
+        }
+    }
+
+    return feedback_arcs;
+}
+
+#[derive(Default)]
+struct Degrees {
+    out_degree: HashMap<usize, usize>,
+    in_degree: HashMap<usize, usize>,
+    out_weight: HashMap<usize, i64>,
+    in_weight: HashMap<usize, i64>,
+}
+
+fn eades_ordering(nodes: &[usize], edges: &[(usize, usize, usize)]) -> Vec<usize> {
+    let mut successors: HashMap<usize, Vec<(usize, usize)>> = HashMap::new();
+    let mut predecessors: HashMap<usize, Vec<(usize, usize)>> = HashMap::new();
+    let mut degrees = Degrees::default();
+
+    for (from, to, w) in edges {
+        // Self loops are always part of the feedback set and don't affect the order.
+        if from == to {
+            continue;
+        }
+        successors.entry(*from).or_default().push((*to, *w));
+        predecessors.entry(*to).or_default().push((*from, *w));
+        *degrees.out_degree.entry(*from).or_default() += 1;
+        *degrees.in_degree.entry(*to).or_default() += 1;
+        *degrees.out_weight.entry(*from).or_default() += *w as i64;
+        *degrees.in_weight.entry(*to).or_default() += *w as i64;
+    }
+
+    let mut remaining: BTreeSet<usize> = nodes.iter().copied().collect();
+    let mut head = Vec::new();
+    let mut tail = Vec::new();
+
+    let remove = |node: usize, remaining: &mut BTreeSet<usize>, degrees: &mut Degrees| {
+        remaining.remove(&node);
+        for (to, w) in successors.get(&node).unwrap_or(&Vec::new()) {
+            if remaining.contains(to) {
+                *degrees.in_degree.entry(*to).or_default() -= 1;
+                *degrees.in_weight.entry(*to).or_default() -= *w as i64;
+            }
+        }
+        for (from, w) in predecessors.get(&node).unwrap_or(&Vec::new()) {
+            if remaining.contains(from) {
+                *degrees.out_degree.entry(*from).or_default() -= 1;
+                *degrees.out_weight.entry(*from).or_default() -= *w as i64;
+            }
+        }
+    };
+
+    while !remaining.is_empty() {
+        let sink = remaining
+            .iter()
+            .find(|node| degrees.out_degree.get(node).copied().unwrap_or(0) == 0)
+            .copied();
+        if let Some(node) = sink {
+            remove(node, &mut remaining, &mut degrees);
+            tail.push(node);
+            continue;
+        }
+
+        let source = remaining
+            .iter()
+            .find(|node| degrees.in_degree.get(node).copied().unwrap_or(0) == 0)
+            .copied();
+        if let Some(node) = source {
+            remove(node, &mut remaining, &mut degrees);
+            head.push(node);
+            continue;
+        }
+
+        let delta = |node: &usize| {
+            return degrees.out_weight.get(node).unwrap_or(&0)
+                - degrees.in_weight.get(node).unwrap_or(&0);
+        };
+        // Prefer the smallest id on ties to keep the result deterministic.
+        let node = *remaining
+            .iter()
+            .max_by(|a, b| delta(a).cmp(&delta(b)).then(b.cmp(a)))
+            .unwrap();
+        remove(node, &mut remaining, &mut degrees);
+        head.push(node);
+    }
+
+    tail.reverse();
+    head.extend(tail);
+    return head;
+}
+
+fn has_path(graph: &HashMap<usize, HashSet<usize>>, from: usize, to: usize) -> bool {
+    let mut visited = HashSet::from([from]);
+    let mut queue = VecDeque::from([from]);
+    while let Some(cur) = queue.pop_front() {
+        if cur == to {
+            return true;
+        }
+        for next in graph.get(&cur).unwrap_or(&HashSet::new()) {
+            if visited.insert(*next) {
+                queue.push_back(*next);
+            }
+        }
+    }
+    return false;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use super::*;
+
+    fn graph(edges: &[(usize, usize)]) -> HashMap<usize, HashSet<usize>> {
+        let mut graph: HashMap<usize, HashSet<usize>> = HashMap::new();
+        for (from, to) in edges {
+            graph.entry(*from).or_default().insert(*to);
+        }
+        return graph;
+    }
+
+    #[test]
+    fn acyclic_graph() {
+        let g = graph(&[(0, 1), (1, 2), (0, 2)]);
+        assert_eq!(feedback_arc_set(3, &g, |_, _| 1), vec![]);
+    }
+
+    #[test]
+    fn picks_lightest_edge() {
+        let g = graph(&[(0, 1), (1, 0)]);
+        let arcs = feedback_arc_set(2, &g, |from, _| if from == 0 { 3 } else { 1 });
+        assert_eq!(arcs, vec![(1, 0)]);
+    }
+
+    #[test]
+    fn self_loop() {
+        let g = graph(&[(0, 0), (0, 1)]);
+        assert_eq!(feedback_arc_set(2, &g, |_, _| 1), vec![(0, 0)]);
+    }
+
+    #[test]
+    fn shared_edge_breaks_multiple_cycles() {
+        // 0 -> 1 -> 2 -> 0 and 0 -> 1 -> 3 -> 0 both go through 0 -> 1.
+        let g = graph(&[(0, 1), (1, 2), (2, 0), (1, 3), (3, 0)]);
+        assert_eq!(feedback_arc_set(4, &g, |_, _| 1), vec![(0, 1)]);
+    }
+}
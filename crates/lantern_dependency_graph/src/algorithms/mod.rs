@@ -0,0 +1,6 @@
+pub mod dominators;
+pub mod elementary_cycles;
+pub mod feedback_arc_set;
+pub mod neighbourhood;
+pub mod shortest_paths;
+pub mod strongly_connected_components;
@@ -0,0 +1,67 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Nodes reachable from `start` in at most `depth` steps, in the order they were reached.
+/// Excluded nodes are neither returned nor walked through, `start` is always included.
+pub fn neighbourhood<F: Fn(usize) -> bool>(
+    start: usize,
+    graph: &HashMap<usize, HashSet<usize>>,
+    depth: Option<usize>,
+    is_excluded: F,
+) -> Vec<usize> {
+    let mut visited = HashSet::from([start]);
+    let mut order = vec![start];
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((cur, distance)) = queue.pop_front() {
+        if depth.is_some_and(|depth| distance >= depth) {
+            continue;
+        }
+
+        let mut next = graph
+            .get(&cur)
+            .unwrap_or(&HashSet::new())
+            .iter()
+            .copied()
+            .collect::<Vec<usize>>();
+        next.sort();
+
+        for node in next {
+            if is_excluded(node) || !visited.insert(node) {
+                continue;
+            }
+            order.push(node);
+            queue.push_back((node, distance + 1));
+        }
+    }
+
+    return order;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_graph(edges: &[(usize, usize)]) -> HashMap<usize, HashSet<usize>> {
+        let mut graph: HashMap<usize, HashSet<usize>> = HashMap::new();
+        for (from, to) in edges {
+            graph.entry(*from).or_default().insert(*to);
+        }
+        return graph;
+    }
+
+    #[test]
+    fn limited_depth() {
+        let graph = build_graph(&[(0, 1), (1, 2), (2, 3), (0, 4)]);
+        assert_eq!(neighbourhood(0, &graph, Some(1), |_| false), vec![0, 1, 4]);
+        assert_eq!(
+            neighbourhood(0, &graph, None, |_| false),
+            vec![0, 1, 4, 2, 3]
+        );
+    }
+
+    #[test]
+    fn excluded_nodes_are_not_walked_through() {
+        let graph = build_graph(&[(0, 1), (1, 2), (0, 3)]);
+        assert_eq!(neighbourhood(0, &graph, None, |node| node == 1), vec![0, 3]);
+    }
+}
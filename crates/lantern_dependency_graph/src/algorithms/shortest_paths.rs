@@ -0,0 +1,145 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+};
+
+/// Up to `limit` distinct simple paths from any of `sources` to any of `targets`, shortest
+/// first. Paths end at the first target they reach.
+///
+/// Partial paths are expanded best first, ordered by their length plus the distance left
+/// to the closest target, so paths come out in order of length without exploring branches
+/// that can't reach a target.
+pub fn shortest_paths(
+    sources: &Vec<usize>,
+    targets: &HashSet<usize>,
+    graph: &HashMap<usize, HashSet<usize>>,
+    limit: usize,
+) -> Vec<Vec<usize>> {
+    let distances = get_distances_to_targets(targets, graph);
+
+    let mut queue = BinaryHeap::new();
+    for source in sources {
+        if let Some(distance) = distances.get(source) {
+            queue.push(Reverse((*distance, vec![*source])));
+        }
+    }
+
+    let mut paths = Vec::new();
+    while let Some(Reverse((_, path))) = queue.pop() {
+        if paths.len() >= limit {
+            break;
+        }
+
+        let last = *path.last().unwrap();
+        if targets.contains(&last) {
+            paths.push(path);
+            continue;
+        }
+
+        let mut next = graph
+            .get(&last)
+            .unwrap_or(&HashSet::new())
+            .iter()
+            .copied()
+            .collect::<Vec<usize>>();
+        next.sort();
+
+        for node in next {
+            let distance = if let Some(distance) = distances.get(&node) {
+                *distance
+            } else {
+                continue;
+            };
+            if path.contains(&node) {
+                continue;
+            }
+            let mut next_path = path.clone();
+            next_path.push(node);
+            queue.push(Reverse((next_path.len() - 1 + distance, next_path)));
+        }
+    }
+
+    return paths;
+}
+
+// Reverse BFS from the targets, nodes that can't reach a target are left out.
+fn get_distances_to_targets(
+    targets: &HashSet<usize>,
+    graph: &HashMap<usize, HashSet<usize>>,
+) -> HashMap<usize, usize> {
+    let mut inverse_graph: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (from, dependencies) in graph {
+        for to in dependencies {
+            inverse_graph.entry(*to).or_default().push(*from);
+        }
+    }
+
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+    for target in targets {
+        distances.insert(*target, 0);
+        queue.push_back(*target);
+    }
+
+    while let Some(cur) = queue.pop_front() {
+        let distance = distances[&cur];
+        for from in inverse_graph.get(&cur).unwrap_or(&Vec::new()) {
+            // Paths end at the first target, so they never go through another target.
+            if !distances.contains_key(from) {
+                distances.insert(*from, distance + 1);
+                queue.push_back(*from);
+            }
+        }
+    }
+
+    return distances;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_graph(edges: &[(usize, usize)]) -> HashMap<usize, HashSet<usize>> {
+        let mut graph: HashMap<usize, HashSet<usize>> = HashMap::new();
+        for (from, to) in edges {
+            graph.entry(*from).or_default().insert(*to);
+        }
+        return graph;
+    }
+
+    #[test]
+    fn shortest_first() {
+        let graph = build_graph(&[(0, 1), (1, 4), (0, 2), (2, 3), (3, 4), (3, 1)]);
+        let targets = HashSet::from([4]);
+        assert_eq!(
+            shortest_paths(&vec![0], &targets, &graph, 1),
+            vec![vec![0, 1, 4]]
+        );
+        assert_eq!(
+            shortest_paths(&vec![0], &targets, &graph, 10),
+            vec![vec![0, 1, 4], vec![0, 2, 3, 4], vec![0, 2, 3, 1, 4]]
+        );
+    }
+
+    #[test]
+    fn cycles_and_unreachable_targets() {
+        let graph = build_graph(&[(0, 1), (1, 0), (1, 2)]);
+        assert_eq!(
+            shortest_paths(&vec![0], &HashSet::from([2]), &graph, 10),
+            vec![vec![0, 1, 2]]
+        );
+        assert_eq!(
+            shortest_paths(&vec![2], &HashSet::from([0]), &graph, 10).len(),
+            0
+        );
+    }
+
+    #[test]
+    fn stops_at_first_target() {
+        let graph = build_graph(&[(0, 1), (1, 2)]);
+        assert_eq!(
+            shortest_paths(&vec![0], &HashSet::from([1, 2]), &graph, 10),
+            vec![vec![0, 1]]
+        );
+    }
+}
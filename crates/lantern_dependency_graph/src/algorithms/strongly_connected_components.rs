@@ -0,0 +1,120 @@
+use std::collections::{HashMap, HashSet};
+
+/// Tarjan's algorithm. Components and their members are sorted by node id so the
+/// result doesn't depend on hash map iteration order.
+pub fn strongly_connected_components(
+    nodes: usize,
+    graph: &HashMap<usize, HashSet<usize>>,
+) -> Vec<Vec<usize>> {
+    struct State {
+        index: usize,
+        indices: Vec<Option<usize>>,
+        low_links: Vec<usize>,
+        on_stack: Vec<bool>,
+        stack: Vec<usize>,
+        components: Vec<Vec<usize>>,
+    }
+
+    fn connect(graph: &HashMap<usize, HashSet<usize>>, state: &mut State, node: usize) {
+        state.indices[node] = Some(state.index);
+        state.low_links[node] = state.index;
+        state.index += 1;
+        state.stack.push(node);
+        state.on_stack[node] = true;
+
+        let mut successors = graph
+            .get(&node)
+            .unwrap_or(&HashSet::new())
+            .iter()
+            .copied()
+            .collect::<Vec<usize>>();
+        successors.sort();
+
+        for to in successors {
+            if let Some(to_index) = state.indices[to] {
+                if state.on_stack[to] {
+                    state.low_links[node] = state.low_links[node].min(to_index);
+                }
+            } else {
+                connect(graph, state, to);
+                state.low_links[node] = state.low_links[node].min(state.low_links[to]);
+            }
+        }
+
+        if Some(state.low_links[node]) == state.indices[node] {
+            let mut component = Vec::new();
+            loop {
+                let member = state.stack.pop().unwrap();
+                state.on_stack[member] = false;
+                component.push(member);
+                if member == node {
+                    break;
+                }
+            }
+            component.sort();
+            state.components.push(component);
+        }
+    }
+
+    let mut state = State {
+        index: 0,
+        indices: vec![None; nodes],
+        low_links: vec![0; nodes],
+        on_stack: vec![false; nodes],
+        stack: Vec::new(),
+        components: Vec::new(),
+    };
+
+    for node in 0..nodes {
+        if state.indices[node].is_none() {
+            connect(graph, &mut state, node);
+        }
+    }
+
+    let mut components = state.components;
+    components.sort_by_key(|component| component[0]);
+    return components;
+}
+
+/// Components files import each other in: ones with more than one member, or a single
+/// member importing itself. Same order as `strongly_connected_components`.
+pub fn cyclic_components(nodes: usize, graph: &HashMap<usize, HashSet<usize>>) -> Vec<Vec<usize>> {
+    return strongly_connected_components(nodes, graph)
+        .into_iter()
+        .filter(|component| {
+            return component.len() > 1
+                || graph
+                    .get(&component[0])
+                    .is_some_and(|successors| successors.contains(&component[0]));
+        })
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use super::*;
+
+    fn graph(edges: &[(usize, usize)]) -> HashMap<usize, HashSet<usize>> {
+        let mut graph: HashMap<usize, HashSet<usize>> = HashMap::new();
+        for (from, to) in edges {
+            graph.entry(*from).or_default().insert(*to);
+        }
+        return graph;
+    }
+
+    #[test]
+    fn finds_components() {
+        let g = graph(&[(0, 1), (1, 2), (2, 1), (2, 3), (3, 4), (4, 3)]);
+        let components = strongly_connected_components(5, &g);
+        assert_eq!(components, vec![vec![0], vec![1, 2], vec![3, 4]]);
+    }
+
+    #[test]
+    fn finds_cyclic_components() {
+        let g = graph(&[(0, 1), (1, 2), (2, 1), (3, 3), (3, 4)]);
+        let components = cyclic_components(5, &g);
+        assert_eq!(components, vec![vec![1, 2], vec![3]]);
+    }
+}
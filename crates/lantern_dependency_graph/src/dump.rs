@@ -0,0 +1,557 @@
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use oxc_span::Span;
+use serde::{Deserialize, Serialize};
+
+use lantern_fs::FileSystem;
+use lantern_symbols_map::symbol::{LNFileReference, LNSymbol, LNSymbolData};
+use lantern_symbols_map::symbols_map::{LNSymbolsMap, LNUnresolvedImport};
+use lantern_symbols_map::LNModule;
+
+use crate::LanternFileDependencyMap;
+
+/// Raw analysis data of a dependency map. Paths are relative to the root the dump was
+/// taken from and every span comes with its line and column, so the dump can be used
+/// without the sources.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LanternDump {
+    pub version: u32,
+    /// Indexed by module id.
+    pub modules: Vec<LanternDumpModule>,
+    /// Indexed by symbol id.
+    pub symbols: Vec<LanternDumpSymbol>,
+    pub unresolved_imports: Vec<LanternDumpUnresolvedImport>,
+    /// Pairs of (from, to) module ids.
+    pub dependencies: Vec<(usize, usize)>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LanternDumpModule {
+    pub path: PathBuf,
+    pub is_entry: bool,
+    pub symbols: Vec<usize>,
+}
+
+/// Symbol with its data spelled out as named fields, fields that don't apply to the kind
+/// are null.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LanternDumpSymbol {
+    pub module_id: usize,
+    pub kind: LanternDumpSymbolKind,
+    /// Local name: the declared name, the binding an import creates or `a` in
+    /// `export { a as b }`. The consequent of a default exported conditional expression.
+    pub name: Option<String>,
+    /// Name other modules import the export by, "default" for default exports.
+    pub export_name: Option<String>,
+    /// Name a named import takes from its source module.
+    pub imported_name: Option<String>,
+    /// Alternate of a default exported conditional expression.
+    pub alternate_name: Option<String>,
+    #[serde(with = "crate::serde_span")]
+    pub span: Span,
+    /// 1-based line and column of the start of `span`, columns count characters.
+    pub line: usize,
+    pub column: usize,
+    pub type_only: bool,
+    /// Module an import or re-export points at.
+    pub source_module: Option<usize>,
+    /// Span of the source string literal.
+    #[serde(with = "crate::serde_span::option")]
+    pub source_span: Option<Span>,
+    /// Span of the statement the symbol was declared in.
+    #[serde(with = "crate::serde_span")]
+    pub declaration_span: Span,
+}
+
+/// One kind per shape of symbol the parser records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LanternDumpSymbolKind {
+    ExportAll,
+    ExportNamed,
+    ExportDecl,
+    ExportFnDecl,
+    ExportClassDecl,
+    ExportEnumDecl,
+    ExportInterfaceDecl,
+    ExportTypeAliasDecl,
+    ExportDefaultExpr,
+    ExportDefaultClassDecl,
+    ExportDefaultFnDecl,
+    ExportDefaultInterfaceDecl,
+    ExportDefaultIdentifier,
+    ExportDefaultCallExpression,
+    ExportDefaultConditionalExpression,
+    ImportDefault,
+    ImportStar,
+    ImportNamed,
+}
+
+/// Import or re-export whose source couldn't be resolved to a file.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LanternDumpUnresolvedImport {
+    pub module_id: usize,
+    pub source: String,
+    /// Span of the source string literal.
+    #[serde(with = "crate::serde_span")]
+    pub span: Span,
+    /// Why the resolver failed, with paths relative to the root.
+    pub error: String,
+    pub position: LanternDumpPosition,
+}
+
+/// 1-based lines and columns of a span, columns count characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LanternDumpPosition {
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LanternDumpFormat {
+    Json,
+    /// Compact bincode encoding.
+    Binary,
+}
+
+impl LanternDump {
+    pub const VERSION: u32 = 3;
+
+    pub fn from_dependency_map(depgraph: &mut LanternFileDependencyMap, root: &Path) -> Self {
+        let ln_map = &mut depgraph.symbols_map;
+        let modules = ln_map
+            .modules
+            .iter()
+            .map(|module| LanternDumpModule {
+                path: module
+                    .file_path
+                    .strip_prefix(root)
+                    .unwrap_or(&module.file_path)
+                    .to_path_buf(),
+                is_entry: module.is_entry,
+                symbols: module.symbols.clone(),
+            })
+            .collect();
+
+        let mut symbols = Vec::new();
+        for symbol_id in 0..ln_map.symbols.len() {
+            let symbol = ln_map.symbols[symbol_id].clone();
+            let (line, column) =
+                ln_map.get_position_from_offset(symbol.module_id, symbol.get_span().start);
+            let declaration_span = *ln_map.get_declaration_span(symbol_id);
+            symbols.push(LanternDumpSymbol::from_symbol(
+                &symbol,
+                line,
+                column,
+                declaration_span,
+            ));
+        }
+
+        let unresolved_imports = ln_map
+            .unresolved_imports
+            .clone()
+            .into_iter()
+            .map(|import| LanternDumpUnresolvedImport {
+                module_id: import.module_id,
+                error: import.get_relative_error(root),
+                position: get_position(ln_map, import.module_id, &import.span),
+                source: import.source,
+                span: import.span,
+            })
+            .collect();
+
+        let mut dependencies = Vec::new();
+        for (from, to) in &depgraph.dependency_map {
+            for to in to {
+                dependencies.push((*from, *to));
+            }
+        }
+        dependencies.sort();
+
+        return Self {
+            version: Self::VERSION,
+            modules,
+            symbols,
+            unresolved_imports,
+            dependencies,
+        };
+    }
+
+    /// Rebuilds the dependency map without parsing anything, module paths are made absolute
+    /// with `root` and sources are read from `fs` when needed.
+    pub fn to_dependency_map(
+        &self,
+        root: &Path,
+        fs: Arc<dyn FileSystem>,
+    ) -> Result<LanternFileDependencyMap> {
+        let mut ln_map = LNSymbolsMap::new(fs);
+        for module in &self.modules {
+            ln_map.add_module(LNModule {
+                file_path: root.join(&module.path),
+                symbols: vec![],
+                is_entry: module.is_entry,
+            });
+        }
+        for dump_symbol in &self.symbols {
+            let symbol_id = ln_map.add_symbol(
+                dump_symbol.module_id,
+                LNSymbol {
+                    module_id: dump_symbol.module_id,
+                    symbol: dump_symbol.to_symbol_data()?,
+                },
+            );
+            ln_map.set_declaration_span(symbol_id, dump_symbol.declaration_span);
+        }
+        for unresolved_import in &self.unresolved_imports {
+            ln_map.add_unresolved_import(LNUnresolvedImport {
+                module_id: unresolved_import.module_id,
+                source: unresolved_import.source.clone(),
+                span: unresolved_import.span,
+                error: unresolved_import.error.clone(),
+            });
+        }
+
+        let mut depgraph = LanternFileDependencyMap::new(ln_map);
+        let mut dependency_map = Default::default();
+        let mut inverse_dependency_map = Default::default();
+        for (from, to) in &self.dependencies {
+            depgraph.add_dependency(&mut dependency_map, &mut inverse_dependency_map, *from, *to);
+        }
+        depgraph.dependency_map = dependency_map;
+        depgraph.inverse_dependency_map = inverse_dependency_map;
+        return Ok(depgraph);
+    }
+
+    pub fn write(&self, writer: &mut dyn Write, format: LanternDumpFormat) -> Result<()> {
+        match format {
+            LanternDumpFormat::Json => {
+                serde_json::to_writer_pretty(&mut *writer, self)?;
+                writer.write_all(b"\n")?;
+            }
+            LanternDumpFormat::Binary => bincode::serialize_into(writer, self)?,
+        }
+        return Ok(());
+    }
+
+    /// Writes the dump as JSON to `path`.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut file = std::fs::File::create(path)
+            .wrap_err_with(|| format!("Couldn't write dump {:?}", path))?;
+        return self.write(&mut file, LanternDumpFormat::Json);
+    }
+
+    /// Reads a dump in either format.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content =
+            std::fs::read(path).wrap_err_with(|| format!("Couldn't read dump {:?}", path))?;
+        return Self::from_bytes(&content)
+            .wrap_err_with(|| format!("Couldn't load dump {:?}", path));
+    }
+
+    /// Paths of modules that directly depend on `path`.
+    pub fn get_dependents(&self, path: &Path) -> Vec<PathBuf> {
+        let module_id = if let Some(id) = self.modules.iter().position(|m| m.path == path) {
+            id
+        } else {
+            return Vec::new();
+        };
+
+        let mut paths = self
+            .dependencies
+            .iter()
+            .filter(|(_, to)| *to == module_id)
+            .map(|(from, _)| self.modules[*from].path.clone())
+            .collect::<Vec<PathBuf>>();
+        paths.sort();
+        paths.dedup();
+        return paths;
+    }
+
+    pub fn from_bytes(content: &[u8]) -> Result<Self> {
+        // JSON dumps are objects, binary dumps start with the version.
+        let is_json = content
+            .iter()
+            .find(|byte| !byte.is_ascii_whitespace())
+            .is_some_and(|byte| *byte == b'{');
+        let version = if is_json {
+            serde_json::from_slice::<serde_json::Value>(content)?
+                .get("version")
+                .and_then(|version| version.as_u64())
+                .unwrap_or(0) as u32
+        } else {
+            bincode::deserialize::<u32>(content)?
+        };
+        if version != Self::VERSION {
+            return Err(eyre!(
+                "Unsupported dump version {}, expected {}",
+                version,
+                Self::VERSION
+            ));
+        }
+
+        let dump: Self = if is_json {
+            serde_json::from_slice(content)?
+        } else {
+            bincode::deserialize(content)?
+        };
+        dump.validate()?;
+        return Ok(dump);
+    }
+
+    /// Checks that every module and symbol id points into the dump, so a corrupt dump is
+    /// an error instead of a panic when it's used.
+    fn validate(&self) -> Result<()> {
+        let check_module = |module_id: usize| -> Result<()> {
+            if module_id >= self.modules.len() {
+                return Err(eyre!("Module id {} out of range in dump", module_id));
+            }
+            return Ok(());
+        };
+
+        for module in &self.modules {
+            for symbol_id in &module.symbols {
+                if *symbol_id >= self.symbols.len() {
+                    return Err(eyre!("Symbol id {} out of range in dump", symbol_id));
+                }
+            }
+        }
+        for symbol in &self.symbols {
+            check_module(symbol.module_id)?;
+            if let Some(source_module) = symbol.source_module {
+                check_module(source_module)?;
+            }
+        }
+        for unresolved_import in &self.unresolved_imports {
+            check_module(unresolved_import.module_id)?;
+        }
+        for (from, to) in &self.dependencies {
+            check_module(*from)?;
+            check_module(*to)?;
+        }
+        return Ok(());
+    }
+}
+
+impl LanternDumpSymbol {
+    pub fn from_symbol(
+        symbol: &LNSymbol,
+        line: usize,
+        column: usize,
+        declaration_span: Span,
+    ) -> Self {
+        use LanternDumpSymbolKind as Kind;
+
+        let (kind, name, imported_name, alternate_name) = match &symbol.symbol {
+            LNSymbolData::ExportAll(_) => (Kind::ExportAll, None, None, None),
+            LNSymbolData::ExportNamed(local, _, _, _) => {
+                (Kind::ExportNamed, Some(local.clone()), None, None)
+            }
+            LNSymbolData::ExportDecl(name, _) => (Kind::ExportDecl, Some(name.clone()), None, None),
+            LNSymbolData::ExportFnDecl(name, _) => {
+                (Kind::ExportFnDecl, Some(name.clone()), None, None)
+            }
+            LNSymbolData::ExportClassDecl(name, _) => {
+                (Kind::ExportClassDecl, Some(name.clone()), None, None)
+            }
+            LNSymbolData::ExportEnumDecl(name, _) => {
+                (Kind::ExportEnumDecl, Some(name.clone()), None, None)
+            }
+            LNSymbolData::ExportInterfaceDecl(name, _) => {
+                (Kind::ExportInterfaceDecl, Some(name.clone()), None, None)
+            }
+            LNSymbolData::ExportTypeAliasDecl(name, _) => {
+                (Kind::ExportTypeAliasDecl, Some(name.clone()), None, None)
+            }
+            LNSymbolData::ExportDefaultExpr(_) => (Kind::ExportDefaultExpr, None, None, None),
+            LNSymbolData::ExportDefaultClassDecl(name, _) => {
+                (Kind::ExportDefaultClassDecl, name.clone(), None, None)
+            }
+            LNSymbolData::ExportDefaultFnDecl(name, _) => {
+                (Kind::ExportDefaultFnDecl, name.clone(), None, None)
+            }
+            LNSymbolData::ExportDefaultInterfaceDecl(name, _) => (
+                Kind::ExportDefaultInterfaceDecl,
+                Some(name.clone()),
+                None,
+                None,
+            ),
+            LNSymbolData::ExportDefaultIdentifier(name, _) => (
+                Kind::ExportDefaultIdentifier,
+                Some(name.clone()),
+                None,
+                None,
+            ),
+            LNSymbolData::ExportDefaultCallExpression(name, _) => {
+                (Kind::ExportDefaultCallExpression, name.clone(), None, None)
+            }
+            LNSymbolData::ExportDefaultConditionalExpression(consequent, alternate, _) => (
+                Kind::ExportDefaultConditionalExpression,
+                consequent.clone(),
+                None,
+                alternate.clone(),
+            ),
+            LNSymbolData::ImportDefault(name, _, _, _) => {
+                (Kind::ImportDefault, Some(name.clone()), None, None)
+            }
+            LNSymbolData::ImportStar(name, _, _, _) => {
+                (Kind::ImportStar, Some(name.clone()), None, None)
+            }
+            LNSymbolData::ImportNamed(local, imported, _, _, _) => (
+                Kind::ImportNamed,
+                Some(local.clone()),
+                Some(imported.clone()),
+                None,
+            ),
+        };
+
+        let file_ref = symbol.get_file_reference();
+        return Self {
+            module_id: symbol.module_id,
+            kind,
+            name,
+            export_name: symbol.get_export_name().map(String::from),
+            imported_name,
+            alternate_name,
+            span: *symbol.get_span(),
+            line,
+            column,
+            type_only: symbol.is_type_only(),
+            source_module: file_ref.map(|file_ref| file_ref.module_id),
+            source_span: file_ref.map(|file_ref| file_ref.span),
+            declaration_span,
+        };
+    }
+
+    /// Fails when a field the kind needs is missing.
+    pub fn to_symbol_data(&self) -> Result<LNSymbolData> {
+        use LanternDumpSymbolKind as Kind;
+
+        let required = |value: &Option<String>, field: &str| -> Result<String> {
+            return value
+                .clone()
+                .ok_or_else(|| eyre!("{:?} symbol is missing {}", self.kind, field));
+        };
+        let source = self
+            .source_module
+            .zip(self.source_span)
+            .map(|(module_id, span)| LNFileReference::new(module_id, span));
+        let required_source = || -> Result<LNFileReference> {
+            return source
+                .clone()
+                .ok_or_else(|| eyre!("{:?} symbol is missing its source", self.kind));
+        };
+        let span = self.span;
+
+        let data = match self.kind {
+            Kind::ExportAll => LNSymbolData::ExportAll(required_source()?),
+            Kind::ExportNamed => LNSymbolData::ExportNamed(
+                required(&self.name, "name")?,
+                required(&self.export_name, "export_name")?,
+                span,
+                source,
+            ),
+            Kind::ExportDecl => LNSymbolData::ExportDecl(required(&self.name, "name")?, span),
+            Kind::ExportFnDecl => LNSymbolData::ExportFnDecl(required(&self.name, "name")?, span),
+            Kind::ExportClassDecl => {
+                LNSymbolData::ExportClassDecl(required(&self.name, "name")?, span)
+            }
+            Kind::ExportEnumDecl => {
+                LNSymbolData::ExportEnumDecl(required(&self.name, "name")?, span)
+            }
+            Kind::ExportInterfaceDecl => {
+                LNSymbolData::ExportInterfaceDecl(required(&self.name, "name")?, span)
+            }
+            Kind::ExportTypeAliasDecl => {
+                LNSymbolData::ExportTypeAliasDecl(required(&self.name, "name")?, span)
+            }
+            Kind::ExportDefaultExpr => LNSymbolData::ExportDefaultExpr(span),
+            Kind::ExportDefaultClassDecl => {
+                LNSymbolData::ExportDefaultClassDecl(self.name.clone(), span)
+            }
+            Kind::ExportDefaultFnDecl => LNSymbolData::ExportDefaultFnDecl(self.name.clone(), span),
+            Kind::ExportDefaultInterfaceDecl => {
+                LNSymbolData::ExportDefaultInterfaceDecl(required(&self.name, "name")?, span)
+            }
+            Kind::ExportDefaultIdentifier => {
+                LNSymbolData::ExportDefaultIdentifier(required(&self.name, "name")?, span)
+            }
+            Kind::ExportDefaultCallExpression => {
+                LNSymbolData::ExportDefaultCallExpression(self.name.clone(), span)
+            }
+            Kind::ExportDefaultConditionalExpression => {
+                LNSymbolData::ExportDefaultConditionalExpression(
+                    self.name.clone(),
+                    self.alternate_name.clone(),
+                    span,
+                )
+            }
+            Kind::ImportDefault => LNSymbolData::ImportDefault(
+                required(&self.name, "name")?,
+                span,
+                required_source()?,
+                self.type_only,
+            ),
+            Kind::ImportStar => LNSymbolData::ImportStar(
+                required(&self.name, "name")?,
+                span,
+                required_source()?,
+                self.type_only,
+            ),
+            Kind::ImportNamed => LNSymbolData::ImportNamed(
+                required(&self.name, "name")?,
+                required(&self.imported_name, "imported_name")?,
+                span,
+                required_source()?,
+                self.type_only,
+            ),
+        };
+        return Ok(data);
+    }
+}
+
+fn get_position(ln_map: &mut LNSymbolsMap, module_id: usize, span: &Span) -> LanternDumpPosition {
+    let (start_line, start_column) = ln_map.get_position_from_offset(module_id, span.start);
+    let (end_line, end_column) = ln_map.get_position_from_offset(module_id, span.end);
+    return LanternDumpPosition {
+        start_line,
+        start_column,
+        end_line,
+        end_column,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    #[test]
+    fn dependents() {
+        let module = |path: &str| LanternDumpModule {
+            path: PathBuf::from(path),
+            is_entry: false,
+            symbols: Vec::new(),
+        };
+        let dump = LanternDump {
+            version: LanternDump::VERSION,
+            modules: vec![module("index.ts"), module("a.ts"), module("b.ts")],
+            symbols: Vec::new(),
+            unresolved_imports: Vec::new(),
+            dependencies: vec![(0, 1), (0, 2), (1, 2)],
+        };
+
+        assert_eq!(
+            dump.get_dependents(Path::new("b.ts")),
+            vec![PathBuf::from("a.ts"), PathBuf::from("index.ts")]
+        );
+        assert_eq!(dump.get_dependents(Path::new("c.ts")).len(), 0);
+    }
+}
@@ -0,0 +1,467 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use serde::Serialize;
+
+use lantern_fs::FileSystem;
+use lantern_symbols_map::symbol::LNSymbolData;
+
+use crate::LanternFileDependencyMap;
+
+/// File level dependency graph prepared for output, with paths relative to a root and
+/// typed edges. Nodes are indexed by module id.
+#[derive(Debug, Clone, Serialize)]
+pub struct LanternGraph {
+    pub nodes: Vec<LanternGraphNode>,
+    pub edges: Vec<LanternGraphEdge>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LanternGraphNode {
+    pub id: usize,
+    pub path: PathBuf,
+    pub is_entry: bool,
+    /// Name of the package the file belongs to, from `node_modules` or the closest
+    /// `package.json`.
+    pub package: Option<String>,
+    /// Names the file exports, empty for grouped nodes.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub exports: Vec<LanternGraphExport>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LanternGraphExport {
+    pub name: String,
+    pub is_unused: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LanternGraphEdge {
+    pub from: usize,
+    pub to: usize,
+    pub kind: LanternEdgeKind,
+    /// Number of file level edges the edge stands for, 1 unless the graph is grouped.
+    pub weight: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LanternEdgeKind {
+    Import,
+    /// Only types are imported, the edge disappears at runtime.
+    TypeImport,
+    ReExport,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LanternGraphGrouping {
+    /// Group files by directory, keeping at most `depth` leading directories when set.
+    Dir(Option<usize>),
+    /// Group files by the package they belong to, files outside of any package are
+    /// grouped by directory.
+    Package,
+}
+
+impl LanternGraph {
+    pub fn from_dependency_map(depgraph: &LanternFileDependencyMap, root: &Path) -> Self {
+        let mut packages = PackageNames::new(depgraph.symbols_map.get_file_system().clone());
+        let nodes = depgraph
+            .symbols_map
+            .modules
+            .iter()
+            .enumerate()
+            .map(|(id, module)| LanternGraphNode {
+                id,
+                path: module
+                    .file_path
+                    .strip_prefix(root)
+                    .unwrap_or(&module.file_path)
+                    .to_path_buf(),
+                is_entry: module.is_entry,
+                package: packages.get_package_name(&module.file_path),
+                exports: get_exports(depgraph, id),
+            })
+            .collect();
+
+        let mut edges = Vec::new();
+        for (from, dependencies) in &depgraph.dependency_map {
+            for to in dependencies {
+                edges.push(LanternGraphEdge {
+                    from: *from,
+                    to: *to,
+                    kind: get_edge_kind(depgraph, *from, *to),
+                    weight: 1,
+                });
+            }
+        }
+        edges.sort_by_key(|edge| (edge.from, edge.to));
+
+        return Self { nodes, edges };
+    }
+
+    /// Collapses nodes into groups, edges between two groups are merged into one edge
+    /// weighted by the number of merged edges. Edges within a group are dropped.
+    pub fn group(&self, grouping: LanternGraphGrouping) -> Self {
+        let mut group_ids: BTreeMap<String, usize> = BTreeMap::new();
+        let mut node_groups = Vec::new();
+        let mut nodes: Vec<LanternGraphNode> = Vec::new();
+        for (node, name) in self.nodes.iter().zip(self.get_node_groups(grouping)) {
+            let group_id = *group_ids.entry(name.clone()).or_insert_with(|| {
+                nodes.push(LanternGraphNode {
+                    id: nodes.len(),
+                    path: PathBuf::from(&name),
+                    is_entry: false,
+                    package: node.package.clone(),
+                    exports: Vec::new(),
+                });
+                return nodes.len() - 1;
+            });
+            let group = &mut nodes[group_id];
+            group.is_entry |= node.is_entry;
+            if group.package != node.package {
+                group.package = None;
+            }
+            node_groups.push(group_id);
+        }
+
+        let mut edges: BTreeMap<(usize, usize), LanternGraphEdge> = BTreeMap::new();
+        for edge in &self.edges {
+            let (from, to) = (node_groups[edge.from], node_groups[edge.to]);
+            if from == to {
+                continue;
+            }
+            edges
+                .entry((from, to))
+                .and_modify(|grouped| {
+                    grouped.kind = merge_edge_kinds(grouped.kind, edge.kind);
+                    grouped.weight += edge.weight;
+                })
+                .or_insert(LanternGraphEdge {
+                    from,
+                    to,
+                    kind: edge.kind,
+                    weight: edge.weight,
+                });
+        }
+
+        return Self {
+            nodes,
+            edges: edges.into_values().collect(),
+        };
+    }
+
+    /// Name of the group each node belongs to, indexed by node id.
+    pub fn get_node_groups(&self, grouping: LanternGraphGrouping) -> Vec<String> {
+        return self
+            .nodes
+            .iter()
+            .map(|node| get_group_name(node, grouping))
+            .collect();
+    }
+
+    pub fn mark_unused_export(&mut self, node_id: usize, name: &str) {
+        for export in &mut self.nodes[node_id].exports {
+            if export.name == name {
+                export.is_unused = true;
+            }
+        }
+    }
+
+    /// Graph with only the given nodes and edges between them, nodes are renumbered
+    /// keeping their relative order.
+    pub fn subgraph(&self, node_ids: &HashSet<usize>) -> Self {
+        let mut new_ids = HashMap::new();
+        let mut nodes = Vec::new();
+        for node in &self.nodes {
+            if node_ids.contains(&node.id) {
+                new_ids.insert(node.id, nodes.len());
+                nodes.push(LanternGraphNode {
+                    id: nodes.len(),
+                    ..node.clone()
+                });
+            }
+        }
+
+        let edges = self
+            .edges
+            .iter()
+            .filter(|edge| new_ids.contains_key(&edge.from) && new_ids.contains_key(&edge.to))
+            .map(|edge| LanternGraphEdge {
+                from: new_ids[&edge.from],
+                to: new_ids[&edge.to],
+                ..edge.clone()
+            })
+            .collect();
+
+        return Self { nodes, edges };
+    }
+
+    pub fn get_dependency_map(&self) -> HashMap<usize, HashSet<usize>> {
+        let mut dependency_map: HashMap<usize, HashSet<usize>> = HashMap::new();
+        for edge in &self.edges {
+            dependency_map.entry(edge.from).or_default().insert(edge.to);
+        }
+        return dependency_map;
+    }
+}
+
+// "src/features/auth/login.ts" with depth 2 -> "src/features". Files from node_modules
+// are grouped by package, since their directories are outside of the root.
+fn get_group_name(node: &LanternGraphNode, grouping: LanternGraphGrouping) -> String {
+    let is_external = node
+        .path
+        .components()
+        .any(|component| component.as_os_str() == "node_modules");
+    match (grouping, &node.package) {
+        (LanternGraphGrouping::Package, Some(package)) => return package.clone(),
+        (LanternGraphGrouping::Dir(_), Some(package)) if is_external => {
+            return format!("node_modules/{}", package);
+        }
+        _ => {}
+    }
+
+    let dir = node.path.parent().unwrap_or(Path::new(""));
+    let depth = match grouping {
+        LanternGraphGrouping::Dir(Some(depth)) => depth,
+        _ => usize::MAX,
+    };
+    let dir = dir.components().take(depth).collect::<PathBuf>();
+    if dir.as_os_str().is_empty() {
+        return ".".to_owned();
+    }
+    return dir.display().to_string();
+}
+
+// A merged edge is a type import only when all merged edges are, and a re-export only
+// when none of them is a runtime import.
+fn merge_edge_kinds(a: LanternEdgeKind, b: LanternEdgeKind) -> LanternEdgeKind {
+    return match (a, b) {
+        (LanternEdgeKind::Import, _) | (_, LanternEdgeKind::Import) => LanternEdgeKind::Import,
+        (LanternEdgeKind::ReExport, _) | (_, LanternEdgeKind::ReExport) => {
+            LanternEdgeKind::ReExport
+        }
+        _ => LanternEdgeKind::TypeImport,
+    };
+}
+
+// Exports declared in the module itself, in the order they're declared.
+fn get_exports(depgraph: &LanternFileDependencyMap, module_id: usize) -> Vec<LanternGraphExport> {
+    let mut exports: Vec<LanternGraphExport> = Vec::new();
+    for symbol_id in &depgraph.symbols_map.modules[module_id].symbols {
+        let symbol = &depgraph.symbols_map.symbols[*symbol_id];
+        if let Some(name) = symbol.get_export_name() {
+            if !exports.iter().any(|export| export.name == name) {
+                exports.push(LanternGraphExport {
+                    name: name.to_owned(),
+                    is_unused: false,
+                });
+            }
+        }
+    }
+    return exports;
+}
+
+// Re-exports win over imports, an edge is a type import only when every import is.
+pub fn get_edge_kind(
+    depgraph: &LanternFileDependencyMap,
+    from: usize,
+    to: usize,
+) -> LanternEdgeKind {
+    let mut kind = LanternEdgeKind::TypeImport;
+    for symbol in depgraph.get_dependency_symbols(from, to) {
+        match &symbol.symbol {
+            LNSymbolData::ExportAll(_) | LNSymbolData::ExportNamed(_, _, _, Some(_)) => {
+                return LanternEdgeKind::ReExport;
+            }
+            LNSymbolData::ImportDefault(_, _, _, type_only)
+            | LNSymbolData::ImportStar(_, _, _, type_only)
+            | LNSymbolData::ImportNamed(_, _, _, _, type_only)
+                if !type_only =>
+            {
+                kind = LanternEdgeKind::Import;
+            }
+            _ => {}
+        }
+    }
+    return kind;
+}
+
+/// Package names by directory, so `package.json` files are read once per directory.
+pub struct PackageNames {
+    fs: Arc<dyn FileSystem>,
+    cache: HashMap<PathBuf, Option<String>>,
+}
+
+impl PackageNames {
+    pub fn new(fs: Arc<dyn FileSystem>) -> Self {
+        return Self {
+            fs,
+            cache: HashMap::new(),
+        };
+    }
+
+    pub fn get_package_name(&mut self, path: &Path) -> Option<String> {
+        if let Some(name) = get_node_modules_package_name(path) {
+            return Some(name);
+        }
+        return self.get_dir_package_name(path.parent()?);
+    }
+
+    fn get_dir_package_name(&mut self, dir: &Path) -> Option<String> {
+        if let Some(name) = self.cache.get(dir) {
+            return name.clone();
+        }
+
+        let name = match read_package_name(self.fs.as_ref(), &dir.join("package.json")) {
+            Some(name) => Some(name),
+            None => dir
+                .parent()
+                .and_then(|parent| self.get_dir_package_name(parent)),
+        };
+        self.cache.insert(dir.to_path_buf(), name.clone());
+        return name;
+    }
+}
+
+// ".../node_modules/@scope/pkg/lib/a.js" -> "@scope/pkg"
+fn get_node_modules_package_name(path: &Path) -> Option<String> {
+    let components = path
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(name) => name.to_str(),
+            _ => None,
+        })
+        .collect::<Vec<&str>>();
+    let node_modules = components.iter().rposition(|c| *c == "node_modules")?;
+    let name = *components.get(node_modules + 1)?;
+    if name.starts_with('@') {
+        let scoped = components.get(node_modules + 2)?;
+        return Some(format!("{}/{}", name, scoped));
+    }
+    return Some(name.to_owned());
+}
+
+fn read_package_name(fs: &dyn FileSystem, package_json: &Path) -> Option<String> {
+    let content = fs.read_to_string(package_json).ok()?;
+    let json: serde_json::Value = serde_json::from_str(&content).ok()?;
+    return json.get("name")?.as_str().map(|name| name.to_owned());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn node_modules_package_name() {
+        assert_eq!(
+            get_node_modules_package_name(Path::new("/p/node_modules/react/index.js")),
+            Some("react".to_owned())
+        );
+        assert_eq!(
+            get_node_modules_package_name(Path::new(
+                "/p/node_modules/a/node_modules/@scope/pkg/lib/a.js"
+            )),
+            Some("@scope/pkg".to_owned())
+        );
+        assert_eq!(
+            get_node_modules_package_name(Path::new("/p/src/a.ts")),
+            None
+        );
+    }
+
+    fn node(id: usize, path: &str, package: Option<&str>) -> LanternGraphNode {
+        return LanternGraphNode {
+            id,
+            path: PathBuf::from(path),
+            is_entry: id == 0,
+            package: package.map(|p| p.to_owned()),
+            exports: Vec::new(),
+        };
+    }
+
+    fn edge(from: usize, to: usize, kind: LanternEdgeKind) -> LanternGraphEdge {
+        return LanternGraphEdge {
+            from,
+            to,
+            kind,
+            weight: 1,
+        };
+    }
+
+    fn build_graph() -> LanternGraph {
+        return LanternGraph {
+            nodes: vec![
+                node(0, "index.ts", Some("app")),
+                node(1, "src/features/auth/login.ts", Some("app")),
+                node(2, "src/features/auth/session.ts", Some("app")),
+                node(3, "src/features/cart/cart.ts", Some("app")),
+                node(4, "/p/node_modules/react/index.js", Some("react")),
+            ],
+            edges: vec![
+                edge(0, 1, LanternEdgeKind::Import),
+                edge(0, 3, LanternEdgeKind::Import),
+                edge(1, 2, LanternEdgeKind::Import),
+                edge(1, 4, LanternEdgeKind::Import),
+                edge(2, 3, LanternEdgeKind::TypeImport),
+                edge(3, 1, LanternEdgeKind::Import),
+                edge(3, 4, LanternEdgeKind::Import),
+            ],
+        };
+    }
+
+    fn describe(graph: &LanternGraph) -> Vec<(String, String, usize)> {
+        let path = |id: usize| graph.nodes[id].path.display().to_string();
+        return graph
+            .edges
+            .iter()
+            .map(|e| (path(e.from), path(e.to), e.weight))
+            .collect();
+    }
+
+    #[test]
+    fn group_by_dir() {
+        let graph = build_graph().group(LanternGraphGrouping::Dir(Some(2)));
+        let edge = |from: &str, to: &str, weight| (from.to_owned(), to.to_owned(), weight);
+        assert_eq!(graph.nodes.len(), 3);
+        assert!(graph.nodes[0].is_entry);
+        assert_eq!(
+            describe(&graph),
+            vec![
+                edge(".", "src/features", 2),
+                edge("src/features", "node_modules/react", 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn group_by_full_dir() {
+        let graph = build_graph().group(LanternGraphGrouping::Dir(None));
+        let auth = graph
+            .nodes
+            .iter()
+            .position(|n| n.path == Path::new("src/features/auth"))
+            .unwrap();
+        let cart = graph
+            .nodes
+            .iter()
+            .position(|n| n.path == Path::new("src/features/cart"))
+            .unwrap();
+        let auth_to_cart = graph
+            .edges
+            .iter()
+            .find(|e| e.from == auth && e.to == cart)
+            .unwrap();
+        assert_eq!(auth_to_cart.kind, LanternEdgeKind::TypeImport);
+        assert!(graph.get_dependency_map()[&cart].contains(&auth));
+    }
+
+    #[test]
+    fn group_by_package() {
+        let graph = build_graph().group(LanternGraphGrouping::Package);
+        let edge = |from: &str, to: &str, weight| (from.to_owned(), to.to_owned(), weight);
+        assert_eq!(describe(&graph), vec![edge("app", "react", 2)]);
+    }
+}
@@ -1,6 +1,11 @@
+pub mod algorithms;
+pub mod dump;
+pub mod graph;
+mod serde_span;
+
 use std::collections::{HashMap, HashSet};
 
-use lantern_symbols_map::symbol::LNSymbolData;
+use lantern_symbols_map::symbol::{LNSymbol, LNSymbolData};
 use lantern_symbols_map::symbols_map::LNSymbolsMap;
 
 #[derive(Debug)]
@@ -67,6 +72,25 @@ impl LanternFileDependencyMap {
         self.inverse_dependency_map = inverse_dependency_map;
     }
 
+    pub fn get_dependency_symbols(&self, from: usize, to: usize) -> Vec<&LNSymbol> {
+        let module = if let Some(module) = self.symbols_map.get_module(from) {
+            module
+        } else {
+            return Vec::new();
+        };
+
+        return module
+            .symbols
+            .iter()
+            .map(|symbol_id| &self.symbols_map.symbols[*symbol_id])
+            .filter(|symbol| {
+                return symbol
+                    .get_file_reference()
+                    .is_some_and(|file_ref| file_ref.module_id == to);
+            })
+            .collect();
+    }
+
     pub fn add_dependency(
         &self,
         dependency_map: &mut HashMap<usize, HashSet<usize>>,
@@ -0,0 +1,47 @@
+//! Serializes spans as `{ "start": .., "end": .. }` byte offsets, for `#[serde(with)]`.
+
+use oxc_span::Span;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize)]
+struct SpanDef {
+    start: u32,
+    end: u32,
+}
+
+pub fn serialize<S: Serializer>(span: &Span, serializer: S) -> Result<S::Ok, S::Error> {
+    return SpanDef {
+        start: span.start,
+        end: span.end,
+    }
+    .serialize(serializer);
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Span, D::Error> {
+    let span = SpanDef::deserialize(deserializer)?;
+    return Ok(Span::new(span.start, span.end));
+}
+
+/// Same encoding for `Option<Span>`.
+pub mod option {
+    use oxc_span::Span;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::SpanDef;
+
+    pub fn serialize<S: Serializer>(span: &Option<Span>, serializer: S) -> Result<S::Ok, S::Error> {
+        return span
+            .map(|span| SpanDef {
+                start: span.start,
+                end: span.end,
+            })
+            .serialize(serializer);
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Span>, D::Error> {
+        let span = Option::<SpanDef>::deserialize(deserializer)?;
+        return Ok(span.map(|span| Span::new(span.start, span.end)));
+    }
+}
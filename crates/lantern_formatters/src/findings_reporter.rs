@@ -0,0 +1,54 @@
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LanternSeverity {
+    Note,
+    Warning,
+    Error,
+}
+
+impl LanternSeverity {
+    pub fn as_str(&self) -> &'static str {
+        return match self {
+            LanternSeverity::Note => "note",
+            LanternSeverity::Warning => "warning",
+            LanternSeverity::Error => "error",
+        };
+    }
+}
+
+/// Kind of problem a finding reports.
+#[derive(Debug, Clone, Copy)]
+pub struct LanternRule {
+    pub id: &'static str,
+    pub description: &'static str,
+    pub severity: LanternSeverity,
+}
+
+/// Region of a file, lines and columns are 1-based and columns count characters.
+#[derive(Debug, Clone)]
+pub struct LanternLocation {
+    /// Relative to the root findings are reported for.
+    pub path: PathBuf,
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+    /// What this location shows, only used for related locations.
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LanternFinding {
+    pub rule: LanternRule,
+    pub message: String,
+    pub location: LanternLocation,
+    /// Other places involved in the finding, e.g. every import of a cycle.
+    pub related_locations: Vec<LanternLocation>,
+    /// Identifies the finding across runs regardless of where it is in the file.
+    pub fingerprint: String,
+}
+
+pub trait LanternFindingsReporter {
+    fn report(&self, findings: &[LanternFinding]) -> String;
+}
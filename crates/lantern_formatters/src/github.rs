@@ -0,0 +1,41 @@
+use crate::findings_reporter::{LanternFinding, LanternFindingsReporter, LanternSeverity};
+
+/// GitHub Actions workflow commands, each finding becomes an annotation on the file.
+pub struct GitHubReporter;
+
+impl LanternFindingsReporter for GitHubReporter {
+    fn report(&self, findings: &[LanternFinding]) -> String {
+        let mut res = Vec::new();
+        for finding in findings {
+            let command = match finding.rule.severity {
+                LanternSeverity::Note => "notice",
+                LanternSeverity::Warning => "warning",
+                LanternSeverity::Error => "error",
+            };
+            let location = &finding.location;
+            res.push(format!(
+                "::{} file={},line={},col={},endLine={},endColumn={},title={}::{}",
+                command,
+                escape_property(&location.path.display().to_string()),
+                location.start_line,
+                location.start_column,
+                location.end_line,
+                location.end_column,
+                escape_property(finding.rule.id),
+                escape_data(&finding.message)
+            ));
+        }
+        return res.join("\n");
+    }
+}
+
+fn escape_data(value: &str) -> String {
+    return value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A");
+}
+
+fn escape_property(value: &str) -> String {
+    return escape_data(value).replace(':', "%3A").replace(',', "%2C");
+}
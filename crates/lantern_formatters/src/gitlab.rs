@@ -0,0 +1,37 @@
+use serde_json::{json, Value};
+
+use crate::findings_reporter::{LanternFinding, LanternFindingsReporter, LanternSeverity};
+
+/// GitLab Code Quality report, a JSON array of issues.
+pub struct GitLabReporter;
+
+impl LanternFindingsReporter for GitLabReporter {
+    fn report(&self, findings: &[LanternFinding]) -> String {
+        let issues = findings
+            .iter()
+            .map(|finding| {
+                let severity = match finding.rule.severity {
+                    LanternSeverity::Note => "info",
+                    LanternSeverity::Warning => "minor",
+                    LanternSeverity::Error => "major",
+                };
+                let location = &finding.location;
+                return json!({
+                    "description": finding.message,
+                    "check_name": finding.rule.id,
+                    // Only has to be unique and stable between pipelines.
+                    "fingerprint": format!("{}:{}", finding.rule.id, finding.fingerprint),
+                    "severity": severity,
+                    "location": {
+                        "path": location.path.display().to_string(),
+                        "positions": {
+                            "begin": { "line": location.start_line, "column": location.start_column },
+                            "end": { "line": location.end_line, "column": location.end_column },
+                        },
+                    },
+                });
+            })
+            .collect::<Vec<Value>>();
+        return serde_json::to_string_pretty(&issues).unwrap();
+    }
+}
@@ -0,0 +1,5 @@
+use lantern_dependency_graph::graph::LanternGraph;
+
+pub trait LanternGraphFormatter {
+    fn format(&self, graph: &LanternGraph) -> String;
+}
@@ -0,0 +1,70 @@
+use lantern_dependency_graph::graph::{LanternEdgeKind, LanternGraph};
+
+use crate::graph_formatter::LanternGraphFormatter;
+
+pub struct GraphMLFormatter;
+
+impl LanternGraphFormatter for GraphMLFormatter {
+    fn format(&self, graph: &LanternGraph) -> String {
+        let mut res = vec![
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#.to_string(),
+            r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#.to_string(),
+            r#"  <key id="path" for="node" attr.name="path" attr.type="string"/>"#.to_string(),
+            r#"  <key id="entry" for="node" attr.name="entry" attr.type="boolean"/>"#.to_string(),
+            r#"  <key id="package" for="node" attr.name="package" attr.type="string"/>"#
+                .to_string(),
+            r#"  <key id="kind" for="edge" attr.name="kind" attr.type="string"/>"#.to_string(),
+            r#"  <key id="weight" for="edge" attr.name="weight" attr.type="int"/>"#.to_string(),
+            r#"  <graph id="G" edgedefault="directed">"#.to_string(),
+        ];
+
+        for node in &graph.nodes {
+            res.push(format!(r#"    <node id="n{}">"#, node.id));
+            res.push(format!(
+                r#"      <data key="path">{}</data>"#,
+                escape(&node.path.display().to_string())
+            ));
+            res.push(format!(
+                r#"      <data key="entry">{}</data>"#,
+                node.is_entry
+            ));
+            if let Some(package) = &node.package {
+                res.push(format!(
+                    r#"      <data key="package">{}</data>"#,
+                    escape(package)
+                ));
+            }
+            res.push("    </node>".to_string());
+        }
+
+        for edge in &graph.edges {
+            let kind = match edge.kind {
+                LanternEdgeKind::Import => "import",
+                LanternEdgeKind::TypeImport => "type-import",
+                LanternEdgeKind::ReExport => "re-export",
+            };
+            res.push(format!(
+                r#"    <edge source="n{}" target="n{}">"#,
+                edge.from, edge.to
+            ));
+            res.push(format!(r#"      <data key="kind">{}</data>"#, kind));
+            res.push(format!(
+                r#"      <data key="weight">{}</data>"#,
+                edge.weight
+            ));
+            res.push("    </edge>".to_string());
+        }
+
+        res.push("  </graph>".to_string());
+        res.push("</graphml>".to_string());
+        return res.join("\n");
+    }
+}
+
+pub(crate) fn escape(value: &str) -> String {
+    return value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;");
+}
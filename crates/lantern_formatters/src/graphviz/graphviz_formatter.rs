@@ -0,0 +1,49 @@
+use lantern_dependency_graph::graph::{LanternEdgeKind, LanternGraph};
+
+use crate::graph_formatter::LanternGraphFormatter;
+
+pub struct GraphvizFormatter;
+
+impl LanternGraphFormatter for GraphvizFormatter {
+    fn format(&self, graph: &LanternGraph) -> String {
+        let mut res = Vec::new();
+        res.push("digraph {".to_string());
+        for node in &graph.nodes {
+            let shape = if node.is_entry { ", shape=box" } else { "" };
+            res.push(format!(
+                "  {} [label={}{}]",
+                node.id,
+                quote(&node.path.display().to_string()),
+                shape
+            ));
+        }
+        for edge in &graph.edges {
+            let mut attributes = Vec::new();
+            match edge.kind {
+                LanternEdgeKind::Import => {}
+                LanternEdgeKind::TypeImport => attributes.push("style=dashed".to_string()),
+                LanternEdgeKind::ReExport => attributes.push("style=bold".to_string()),
+            };
+            if edge.weight > 1 {
+                attributes.push(format!("label=\"{}\"", edge.weight));
+            }
+
+            if attributes.is_empty() {
+                res.push(format!("  {} -> {}", edge.from, edge.to));
+            } else {
+                res.push(format!(
+                    "  {} -> {} [{}]",
+                    edge.from,
+                    edge.to,
+                    attributes.join(", ")
+                ));
+            }
+        }
+        res.push("}".to_string());
+        return res.join("\n");
+    }
+}
+
+fn quote(value: &str) -> String {
+    return format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""));
+}
@@ -1 +1 @@
-pub mod file_dependency_map_to_graphviz;
+pub mod graphviz_formatter;
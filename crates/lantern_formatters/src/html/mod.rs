@@ -0,0 +1,52 @@
+use serde_json::json;
+
+use lantern_dependency_graph::algorithms::strongly_connected_components::strongly_connected_components;
+use lantern_dependency_graph::graph::LanternGraph;
+
+use crate::graph_formatter::LanternGraphFormatter;
+
+const TEMPLATE: &str = include_str!("template.html");
+const DATA_PLACEHOLDER: &str = "/*LANTERN_GRAPH_DATA*/null";
+
+/// Single self-contained HTML page with the graph data and a viewer embedded, so it can
+/// be opened offline without Graphviz.
+pub struct HtmlFormatter;
+
+impl LanternGraphFormatter for HtmlFormatter {
+    fn format(&self, graph: &LanternGraph) -> String {
+        // Nodes in a strongly connected component with more than one node are in a cycle.
+        let mut cycles: Vec<Option<usize>> = vec![None; graph.nodes.len()];
+        let components =
+            strongly_connected_components(graph.nodes.len(), &graph.get_dependency_map())
+                .into_iter()
+                .filter(|component| component.len() > 1);
+        for (idx, component) in components.enumerate() {
+            for node in component {
+                cycles[node] = Some(idx);
+            }
+        }
+
+        let nodes = graph
+            .nodes
+            .iter()
+            .map(|node| {
+                return json!({
+                    "id": node.id,
+                    "path": node.path,
+                    "isEntry": node.is_entry,
+                    "package": node.package,
+                    "cycle": cycles[node.id],
+                    "exports": node.exports.iter().map(|export| json!({
+                        "name": export.name,
+                        "isUnused": export.is_unused,
+                    })).collect::<Vec<_>>(),
+                });
+            })
+            .collect::<Vec<_>>();
+        let data = json!({ "nodes": nodes, "edges": graph.edges });
+
+        // "</script>" inside of the data would end the script tag early.
+        let data = serde_json::to_string(&data).unwrap().replace("</", "<\\/");
+        return TEMPLATE.replace(DATA_PLACEHOLDER, &data);
+    }
+}
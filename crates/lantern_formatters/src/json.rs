@@ -0,0 +1,11 @@
+use lantern_dependency_graph::graph::LanternGraph;
+
+use crate::graph_formatter::LanternGraphFormatter;
+
+pub struct JsonFormatter;
+
+impl LanternGraphFormatter for JsonFormatter {
+    fn format(&self, graph: &LanternGraph) -> String {
+        return serde_json::to_string_pretty(graph).unwrap();
+    }
+}
@@ -0,0 +1,71 @@
+use crate::findings_reporter::{LanternFinding, LanternFindingsReporter, LanternLocation};
+use crate::graphml::escape;
+
+/// JUnit XML with a test suite per rule and a failed test case per finding.
+pub struct JUnitReporter;
+
+impl LanternFindingsReporter for JUnitReporter {
+    fn report(&self, findings: &[LanternFinding]) -> String {
+        let mut suites: Vec<(&str, Vec<&LanternFinding>)> = Vec::new();
+        for finding in findings {
+            match suites.iter_mut().find(|(id, _)| *id == finding.rule.id) {
+                Some((_, suite)) => suite.push(finding),
+                None => suites.push((finding.rule.id, vec![finding])),
+            }
+        }
+
+        let mut res = vec![
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#.to_string(),
+            format!(
+                r#"<testsuites name="lantern" tests="{}" failures="{}">"#,
+                findings.len(),
+                findings.len()
+            ),
+        ];
+        for (rule_id, suite) in &suites {
+            res.push(format!(
+                r#"  <testsuite name="{}" tests="{}" failures="{}">"#,
+                escape(rule_id),
+                suite.len(),
+                suite.len()
+            ));
+            for finding in suite {
+                let location = &finding.location;
+                let path = escape(&location.path.display().to_string());
+                res.push(format!(
+                    r#"    <testcase name="{}" classname="{}" file="{}" line="{}">"#,
+                    escape(&finding.fingerprint),
+                    path,
+                    path,
+                    location.start_line
+                ));
+                let mut details = vec![format_location(location, &finding.message)];
+                for related in &finding.related_locations {
+                    let message = related.message.as_deref().unwrap_or_default();
+                    details.push(format_location(related, message));
+                }
+                res.push(format!(
+                    r#"      <failure type="{}" message="{}">{}</failure>"#,
+                    escape(rule_id),
+                    escape(&finding.message),
+                    escape(&details.join("\n"))
+                ));
+                res.push("    </testcase>".to_string());
+            }
+            res.push("  </testsuite>".to_string());
+        }
+        res.push("</testsuites>".to_string());
+
+        return res.join("\n");
+    }
+}
+
+fn format_location(location: &LanternLocation, message: &str) -> String {
+    return format!(
+        "{}:{}:{}: {}",
+        location.path.display(),
+        location.start_line,
+        location.start_column,
+        message
+    );
+}
@@ -1 +1,11 @@
+pub mod findings_reporter;
+pub mod github;
+pub mod gitlab;
+pub mod graph_formatter;
+pub mod graphml;
 pub mod graphviz;
+pub mod html;
+pub mod json;
+pub mod junit;
+pub mod mermaid;
+pub mod sarif;
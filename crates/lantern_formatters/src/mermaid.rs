@@ -0,0 +1,51 @@
+use lantern_dependency_graph::graph::{LanternEdgeKind, LanternGraph};
+
+use crate::graph_formatter::LanternGraphFormatter;
+
+/// Mermaid flowchart, type imports are dotted and re-exports are thick arrows.
+pub struct MermaidFormatter;
+
+impl LanternGraphFormatter for MermaidFormatter {
+    fn format(&self, graph: &LanternGraph) -> String {
+        let mut res = Vec::new();
+        res.push("flowchart LR".to_string());
+        for node in &graph.nodes {
+            res.push(format!(
+                "  n{}[\"{}\"]",
+                node.id,
+                escape(&node.path.display().to_string())
+            ));
+        }
+        for edge in &graph.edges {
+            let arrow = match edge.kind {
+                LanternEdgeKind::Import => "-->",
+                LanternEdgeKind::TypeImport => "-.->",
+                LanternEdgeKind::ReExport => "==>",
+            };
+            if edge.weight > 1 {
+                res.push(format!(
+                    "  n{} {}|{}| n{}",
+                    edge.from, arrow, edge.weight, edge.to
+                ));
+            } else {
+                res.push(format!("  n{} {} n{}", edge.from, arrow, edge.to));
+            }
+        }
+
+        let entries = graph
+            .nodes
+            .iter()
+            .filter(|node| node.is_entry)
+            .map(|node| format!("n{}", node.id))
+            .collect::<Vec<String>>();
+        if !entries.is_empty() {
+            res.push("  classDef entry stroke-width:3px".to_string());
+            res.push(format!("  class {} entry", entries.join(",")));
+        }
+        return res.join("\n");
+    }
+}
+
+fn escape(value: &str) -> String {
+    return value.replace('"', "#quot;");
+}
@@ -0,0 +1,124 @@
+use std::path::{Path, PathBuf};
+
+use serde_json::{json, Value};
+
+use crate::findings_reporter::{
+    LanternFinding, LanternFindingsReporter, LanternLocation, LanternRule,
+};
+
+/// SARIF 2.1.0 log with one run, file paths are relative to `%SRCROOT%`.
+pub struct SarifReporter {
+    root: PathBuf,
+}
+
+impl SarifReporter {
+    pub fn new(root: &Path) -> Self {
+        return Self {
+            root: root.to_path_buf(),
+        };
+    }
+}
+
+impl LanternFindingsReporter for SarifReporter {
+    fn report(&self, findings: &[LanternFinding]) -> String {
+        let mut rules: Vec<LanternRule> = Vec::new();
+        let mut results = Vec::new();
+
+        for finding in findings {
+            let rule_index = match rules.iter().position(|rule| rule.id == finding.rule.id) {
+                Some(idx) => idx,
+                None => {
+                    rules.push(finding.rule);
+                    rules.len() - 1
+                }
+            };
+
+            let mut result = json!({
+                "ruleId": finding.rule.id,
+                "ruleIndex": rule_index,
+                "level": finding.rule.severity.as_str(),
+                "message": { "text": finding.message },
+                "locations": [to_physical_location(&finding.location)],
+                "partialFingerprints": { "lanternKey/v1": finding.fingerprint },
+            });
+            if !finding.related_locations.is_empty() {
+                result["relatedLocations"] = finding
+                    .related_locations
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, location)| {
+                        let mut related = to_physical_location(location);
+                        related["id"] = json!(idx);
+                        if let Some(message) = &location.message {
+                            related["message"] = json!({ "text": message });
+                        }
+                        return related;
+                    })
+                    .collect();
+            }
+            results.push(result);
+        }
+
+        let rules = rules
+            .iter()
+            .map(|rule| {
+                return json!({
+                    "id": rule.id,
+                    "shortDescription": { "text": rule.description },
+                    "defaultConfiguration": { "level": rule.severity.as_str() },
+                });
+            })
+            .collect::<Vec<Value>>();
+
+        let log = json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": { "driver": { "name": "lantern", "rules": rules } },
+                "originalUriBaseIds": {
+                    "%SRCROOT%": { "uri": to_uri(&self.root.to_string_lossy(), true) },
+                },
+                "columnKind": "unicodeCodePoints",
+                "results": results,
+            }],
+        });
+        return serde_json::to_string_pretty(&log).unwrap();
+    }
+}
+
+fn to_physical_location(location: &LanternLocation) -> Value {
+    return json!({
+        "physicalLocation": {
+            "artifactLocation": {
+                "uri": to_uri(&location.path.to_string_lossy(), false),
+                "uriBaseId": "%SRCROOT%",
+            },
+            "region": {
+                "startLine": location.start_line,
+                "startColumn": location.start_column,
+                "endLine": location.end_line,
+                "endColumn": location.end_column,
+            },
+        },
+    });
+}
+
+/// Relative paths become relative URI references, absolute directories `file://` URIs
+/// ending with a slash as SARIF requires for base ids.
+fn to_uri(path: &str, is_base: bool) -> String {
+    let mut uri = path
+        .replace('\\', "/")
+        .replace('%', "%25")
+        .replace(' ', "%20")
+        .replace('#', "%23");
+    if is_base {
+        if !uri.starts_with('/') {
+            uri.insert(0, '/');
+        }
+        uri = format!("file://{}", uri);
+        if !uri.ends_with('/') {
+            uri.push('/');
+        }
+    }
+    return uri;
+}
@@ -0,0 +1,65 @@
+use std::{
+    fmt::Debug,
+    io,
+    path::{Component, Path, PathBuf},
+};
+
+mod memory;
+mod os;
+mod overlay;
+
+pub use memory::MemoryFileSystem;
+pub use os::OsFileSystem;
+pub use overlay::OverlayFileSystem;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Metadata {
+    pub is_file: bool,
+    pub is_dir: bool,
+    pub is_symlink: bool,
+}
+
+/// Source of the files a project is analysed from, the disk, memory or a mix of both.
+pub trait FileSystem: Debug + Send + Sync {
+    fn read_to_string(&self, path: &Path) -> io::Result<String>;
+
+    fn metadata(&self, path: &Path) -> io::Result<Metadata>;
+
+    /// Same as `metadata`, but doesn't follow a symlink at `path`.
+    fn symlink_metadata(&self, path: &Path) -> io::Result<Metadata>;
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf>;
+
+    /// Paths of the entries of a directory, sorted.
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>>;
+
+    fn is_file(&self, path: &Path) -> bool {
+        return self.metadata(path).is_ok_and(|metadata| metadata.is_file);
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        return self.metadata(path).is_ok_and(|metadata| metadata.is_dir);
+    }
+}
+
+pub(crate) fn not_found(path: &Path) -> io::Error {
+    return io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("{} not found", path.display()),
+    );
+}
+
+/// Resolves "." and ".." without touching the file system, symlinks are not followed.
+pub fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component.as_os_str()),
+        }
+    }
+    return normalized;
+}
@@ -0,0 +1,147 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::{normalize, not_found, FileSystem, Metadata};
+
+/// Files kept in memory, directories exist as long as they contain a file.
+#[derive(Debug, Default, Clone)]
+pub struct MemoryFileSystem {
+    files: HashMap<PathBuf, String>,
+    dirs: HashSet<PathBuf>,
+}
+
+impl MemoryFileSystem {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Adds or replaces a file, `path` must be absolute.
+    pub fn add_file(&mut self, path: &Path, source: String) {
+        let path = normalize(path);
+        for dir in path.ancestors().skip(1) {
+            self.dirs.insert(dir.to_path_buf());
+        }
+        self.files.insert(path, source);
+    }
+
+    /// Removes a file and the directories left without files.
+    pub fn remove_file(&mut self, path: &Path) -> Option<String> {
+        let path = normalize(path);
+        let source = self.files.remove(&path)?;
+        for dir in path.ancestors().skip(1) {
+            if self.files.keys().any(|file| file.starts_with(dir)) {
+                break;
+            }
+            self.dirs.remove(dir);
+        }
+        return Some(source);
+    }
+
+    pub fn has_file(&self, path: &Path) -> bool {
+        return self.files.contains_key(&normalize(path));
+    }
+}
+
+impl FileSystem for MemoryFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        return self
+            .files
+            .get(&normalize(path))
+            .cloned()
+            .ok_or_else(|| not_found(path));
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<Metadata> {
+        let path = normalize(path);
+        let is_file = self.files.contains_key(&path);
+        let is_dir = self.dirs.contains(&path);
+        if !is_file && !is_dir {
+            return Err(not_found(&path));
+        }
+        return Ok(Metadata {
+            is_file,
+            is_dir,
+            is_symlink: false,
+        });
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<Metadata> {
+        return self.metadata(path);
+    }
+
+    // There are no symlinks in memory, so ".." can be resolved lexically.
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        self.metadata(path)?;
+        return Ok(normalize(path));
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let path = normalize(path);
+        if !self.dirs.contains(&path) {
+            return Err(not_found(&path));
+        }
+        let mut entries = self
+            .files
+            .keys()
+            .chain(self.dirs.iter())
+            .filter(|entry| entry.parent() == Some(path.as_path()))
+            .cloned()
+            .collect::<Vec<PathBuf>>();
+        entries.sort();
+        return Ok(entries);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn files_and_dirs() {
+        let mut fs = MemoryFileSystem::new();
+        fs.add_file(Path::new("/p/src/a.ts"), "a".to_string());
+
+        assert_eq!(fs.read_to_string(Path::new("/p/src/a.ts")).unwrap(), "a");
+        assert!(fs.is_file(Path::new("/p/src/a.ts")));
+        assert!(fs.is_dir(Path::new("/p/src")));
+        assert!(fs.is_dir(Path::new("/p")));
+        assert!(!fs.is_file(Path::new("/p/src/b.ts")));
+        assert!(fs.read_to_string(Path::new("/p/src/b.ts")).is_err());
+        assert_eq!(
+            fs.read_dir(Path::new("/p")).unwrap(),
+            vec![PathBuf::from("/p/src")]
+        );
+    }
+
+    #[test]
+    fn remove_file_prunes_dirs() {
+        let mut fs = MemoryFileSystem::new();
+        fs.add_file(Path::new("/p/a.ts"), "a".to_string());
+        fs.add_file(Path::new("/p/src/lib/b.ts"), "b".to_string());
+
+        assert_eq!(fs.remove_file(Path::new("/p/src/lib/b.ts")).unwrap(), "b");
+        assert!(!fs.is_dir(Path::new("/p/src/lib")));
+        assert!(!fs.is_dir(Path::new("/p/src")));
+        assert!(fs.is_dir(Path::new("/p")));
+        assert_eq!(
+            fs.read_dir(Path::new("/p")).unwrap(),
+            vec![PathBuf::from("/p/a.ts")]
+        );
+        assert!(fs.remove_file(Path::new("/p/src/lib/b.ts")).is_none());
+    }
+
+    #[test]
+    fn canonicalize() {
+        let mut fs = MemoryFileSystem::new();
+        fs.add_file(Path::new("/p/src/a.ts"), "a".to_string());
+
+        assert_eq!(
+            fs.canonicalize(Path::new("/p/lib/../src/./a.ts")).unwrap(),
+            PathBuf::from("/p/src/a.ts")
+        );
+        assert!(fs.canonicalize(Path::new("/p/lib/a.ts")).is_err());
+    }
+}
@@ -0,0 +1,43 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::{FileSystem, Metadata};
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OsFileSystem;
+
+impl FileSystem for OsFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        return std::fs::read_to_string(path);
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<Metadata> {
+        return std::fs::metadata(path).map(to_metadata);
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<Metadata> {
+        return std::fs::symlink_metadata(path).map(to_metadata);
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        return std::fs::canonicalize(path);
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let mut entries = std::fs::read_dir(path)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<Vec<PathBuf>>>()?;
+        entries.sort();
+        return Ok(entries);
+    }
+}
+
+fn to_metadata(metadata: std::fs::Metadata) -> Metadata {
+    return Metadata {
+        is_file: metadata.is_file(),
+        is_dir: metadata.is_dir(),
+        is_symlink: metadata.file_type().is_symlink(),
+    };
+}
@@ -0,0 +1,87 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::{FileSystem, Metadata};
+
+/// Reads files from `upper` first and falls back to `lower`, e.g. unsaved editor buffers
+/// over the disk.
+#[derive(Debug, Clone)]
+pub struct OverlayFileSystem {
+    upper: Arc<dyn FileSystem>,
+    lower: Arc<dyn FileSystem>,
+}
+
+impl OverlayFileSystem {
+    pub fn new(upper: Arc<dyn FileSystem>, lower: Arc<dyn FileSystem>) -> Self {
+        return Self { upper, lower };
+    }
+}
+
+impl FileSystem for OverlayFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        return self
+            .upper
+            .read_to_string(path)
+            .or_else(|_| self.lower.read_to_string(path));
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<Metadata> {
+        return self
+            .upper
+            .metadata(path)
+            .or_else(|_| self.lower.metadata(path));
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<Metadata> {
+        return self
+            .upper
+            .symlink_metadata(path)
+            .or_else(|_| self.lower.symlink_metadata(path));
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        return self
+            .upper
+            .canonicalize(path)
+            .or_else(|_| self.lower.canonicalize(path));
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let mut entries = match (self.upper.read_dir(path), self.lower.read_dir(path)) {
+            (Ok(upper), Ok(lower)) => upper.into_iter().chain(lower).collect(),
+            (Ok(entries), Err(_)) | (Err(_), Ok(entries)) => entries,
+            (Err(err), Err(_)) => return Err(err),
+        };
+        entries.sort();
+        entries.dedup();
+        return Ok(entries);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MemoryFileSystem;
+
+    #[test]
+    fn upper_takes_precedence() {
+        let mut upper = MemoryFileSystem::new();
+        upper.add_file(Path::new("/p/a.ts"), "upper".to_string());
+        let mut lower = MemoryFileSystem::new();
+        lower.add_file(Path::new("/p/a.ts"), "lower".to_string());
+        lower.add_file(Path::new("/p/b.ts"), "lower".to_string());
+        let fs = OverlayFileSystem::new(Arc::new(upper), Arc::new(lower));
+
+        assert_eq!(fs.read_to_string(Path::new("/p/a.ts")).unwrap(), "upper");
+        assert_eq!(fs.read_to_string(Path::new("/p/b.ts")).unwrap(), "lower");
+        assert!(fs.is_file(Path::new("/p/b.ts")));
+        assert!(fs.read_to_string(Path::new("/p/c.ts")).is_err());
+        assert_eq!(
+            fs.read_dir(Path::new("/p")).unwrap(),
+            vec![PathBuf::from("/p/a.ts"), PathBuf::from("/p/b.ts")]
+        );
+    }
+}
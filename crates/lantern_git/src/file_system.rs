@@ -0,0 +1,196 @@
+use std::{
+    ffi::OsString,
+    fmt, io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use color_eyre::eyre::{Result, WrapErr};
+use git2::{ObjectType, Oid, Repository};
+
+use lantern_fs::{normalize, FileSystem, Metadata};
+
+use crate::open_repository;
+
+// Git stores symlinks as blobs with their target as the content.
+const SYMLINK_FILEMODE: i32 = 0o120000;
+const MAX_SYMLINK_DEPTH: usize = 32;
+
+/// Files of a revision read straight from the git object database, the working tree is
+/// never touched. Paths are absolute paths inside the repository's working directory.
+pub struct GitFileSystem {
+    repo: Mutex<Repository>,
+    tree: Oid,
+    workdir: PathBuf,
+}
+
+enum GitEntry {
+    Dir(Oid),
+    File(Oid),
+    Symlink(Oid),
+}
+
+impl GitFileSystem {
+    /// Opens the repository containing `path` at revision `rev`, e.g. "main" or a sha.
+    pub fn open(path: &Path, rev: &str) -> Result<Self> {
+        let (repo, workdir) = open_repository(path)?;
+        let tree = repo
+            .revparse_single(rev)
+            .and_then(|object| object.peel_to_tree())
+            .wrap_err_with(|| format!("Couldn't find revision {:?}", rev))?
+            .id();
+        return Ok(Self {
+            repo: Mutex::new(repo),
+            tree,
+            workdir,
+        });
+    }
+
+    pub fn get_workdir(&self) -> &Path {
+        return &self.workdir;
+    }
+
+    fn get_entry(&self, path: &Path) -> io::Result<GitEntry> {
+        let path = normalize(path);
+        let relative = path
+            .strip_prefix(&self.workdir)
+            .map_err(|_| not_found(&path))?;
+        if relative.as_os_str().is_empty() {
+            return Ok(GitEntry::Dir(self.tree));
+        }
+
+        let repo = self.repo.lock().unwrap();
+        let tree = repo.find_tree(self.tree).map_err(to_io_error)?;
+        let entry = tree.get_path(relative).map_err(|_| not_found(&path))?;
+        return match entry.kind() {
+            Some(ObjectType::Tree) => Ok(GitEntry::Dir(entry.id())),
+            Some(ObjectType::Blob) if entry.filemode() == SYMLINK_FILEMODE => {
+                Ok(GitEntry::Symlink(entry.id()))
+            }
+            Some(ObjectType::Blob) => Ok(GitEntry::File(entry.id())),
+            // Submodules aren't part of the revision's tree.
+            _ => Err(not_found(&path)),
+        };
+    }
+
+    fn read_blob(&self, oid: Oid) -> io::Result<String> {
+        let repo = self.repo.lock().unwrap();
+        let blob = repo.find_blob(oid).map_err(to_io_error)?;
+        return String::from_utf8(blob.content().to_vec())
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err));
+    }
+
+    // Follows symlinks in every component of `path`, so paths through symlinked
+    // directories resolve too. Components that don't exist are kept as they are.
+    fn resolve_symlinks(&self, path: &Path) -> io::Result<PathBuf> {
+        let mut remaining = components_reversed(&normalize(path));
+        let mut resolved = PathBuf::new();
+        let mut followed = 0;
+        while let Some(component) = remaining.pop() {
+            let next = resolved.join(&component);
+            let target = match self.get_entry(&next) {
+                Ok(GitEntry::Symlink(oid)) => self.read_blob(oid)?,
+                _ => {
+                    resolved = next;
+                    continue;
+                }
+            };
+
+            followed += 1;
+            if followed > MAX_SYMLINK_DEPTH {
+                return Err(io::Error::other(format!(
+                    "Too many levels of symbolic links at {}",
+                    path.display()
+                )));
+            }
+            // The target replaces the link, the rest of the path is resolved against it.
+            remaining.extend(components_reversed(&normalize(
+                &resolved.join(target.trim_end()),
+            )));
+            resolved = PathBuf::new();
+        }
+        return Ok(resolved);
+    }
+}
+
+fn components_reversed(path: &Path) -> Vec<OsString> {
+    return path
+        .components()
+        .rev()
+        .map(|component| component.as_os_str().to_os_string())
+        .collect();
+}
+
+impl fmt::Debug for GitFileSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return f
+            .debug_struct("GitFileSystem")
+            .field("tree", &self.tree)
+            .field("workdir", &self.workdir)
+            .finish();
+    }
+}
+
+impl FileSystem for GitFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        return match self.get_entry(&self.resolve_symlinks(path)?)? {
+            GitEntry::File(oid) => self.read_blob(oid),
+            _ => Err(io::Error::other(format!(
+                "{} is not a file",
+                path.display()
+            ))),
+        };
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<Metadata> {
+        return self.symlink_metadata(&self.resolve_symlinks(path)?);
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<Metadata> {
+        let entry = self.get_entry(path)?;
+        return Ok(Metadata {
+            is_file: matches!(entry, GitEntry::File(_)),
+            is_dir: matches!(entry, GitEntry::Dir(_)),
+            is_symlink: matches!(entry, GitEntry::Symlink(_)),
+        });
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        let path = self.resolve_symlinks(path)?;
+        self.get_entry(&path)?;
+        return Ok(path);
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let path = self.resolve_symlinks(path)?;
+        let oid = match self.get_entry(&path)? {
+            GitEntry::Dir(oid) => oid,
+            _ => {
+                return Err(io::Error::other(format!(
+                    "{} is not a directory",
+                    path.display()
+                )))
+            }
+        };
+
+        let repo = self.repo.lock().unwrap();
+        let tree = repo.find_tree(oid).map_err(to_io_error)?;
+        let mut entries = tree
+            .iter()
+            .filter_map(|entry| entry.name().map(|name| path.join(name)))
+            .collect::<Vec<PathBuf>>();
+        entries.sort();
+        return Ok(entries);
+    }
+}
+
+fn not_found(path: &Path) -> io::Error {
+    return io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("{} not found", path.display()),
+    );
+}
+
+fn to_io_error(err: git2::Error) -> io::Error {
+    return io::Error::other(err);
+}
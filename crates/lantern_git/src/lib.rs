@@ -0,0 +1,151 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use git2::{Delta, Diff, DiffFindOptions, DiffOptions, Repository};
+
+mod file_system;
+
+pub use file_system::GitFileSystem;
+
+#[derive(Debug, Default)]
+pub struct LanternChangedFiles {
+    /// Added, modified and untracked files.
+    pub modified: Vec<PathBuf>,
+    pub deleted: Vec<PathBuf>,
+    /// Pairs of (old path, new path).
+    pub renamed: Vec<(PathBuf, PathBuf)>,
+}
+
+impl LanternChangedFiles {
+    /// Every path touched by the change, including the old side of renames.
+    pub fn all(&self) -> Vec<PathBuf> {
+        let mut all = self.modified.clone();
+        all.extend(self.deleted.iter().cloned());
+        for (from, to) in &self.renamed {
+            all.push(from.clone());
+            all.push(to.clone());
+        }
+        all.sort();
+        all.dedup();
+        return all;
+    }
+}
+
+/// Finds files changed in the repository containing `path` compared to the merge base
+/// of `since` and HEAD, including staged, unstaged and untracked changes.
+///
+/// Only the local `.git` directory is read, all returned paths are absolute.
+pub fn get_changed_files(path: &Path, since: &str) -> Result<LanternChangedFiles> {
+    let (repo, workdir) = open_repository(path)?;
+
+    let mut diff_options = DiffOptions::new();
+    diff_options
+        .include_untracked(true)
+        .recurse_untracked_dirs(true);
+    let mut diff = diff_since(&repo, since, &mut diff_options)?;
+
+    let mut find_options = DiffFindOptions::new();
+    find_options.renames(true).for_untracked(true);
+    diff.find_similar(Some(&mut find_options))?;
+
+    let mut changed_files = LanternChangedFiles::default();
+    for delta in diff.deltas() {
+        let old_path = delta.old_file().path().map(|p| workdir.join(p));
+        let new_path = delta.new_file().path().map(|p| workdir.join(p));
+
+        match (delta.status(), old_path, new_path) {
+            (Delta::Deleted, Some(old_path), _) => {
+                changed_files.deleted.push(old_path);
+            }
+            (Delta::Renamed, Some(old_path), Some(new_path)) => {
+                changed_files.renamed.push((old_path, new_path));
+            }
+            (
+                Delta::Added
+                | Delta::Copied
+                | Delta::Modified
+                | Delta::Typechange
+                | Delta::Untracked,
+                _,
+                Some(new_path),
+            ) => {
+                changed_files.modified.push(new_path);
+            }
+            _ => {}
+        }
+    }
+
+    return Ok(changed_files);
+}
+
+/// Lines changed since the merge base of `since` and HEAD as 1-based inclusive ranges in
+/// the current version of each file. Lines that were only removed are attributed to the
+/// line preceding them, deleted files are not included.
+pub fn get_changed_lines(
+    path: &Path,
+    since: &str,
+) -> Result<HashMap<PathBuf, Vec<(usize, usize)>>> {
+    let (repo, workdir) = open_repository(path)?;
+
+    let mut diff_options = DiffOptions::new();
+    diff_options
+        .include_untracked(true)
+        .recurse_untracked_dirs(true)
+        .show_untracked_content(true)
+        .context_lines(0);
+    let diff = diff_since(&repo, since, &mut diff_options)?;
+
+    let mut changed_lines: HashMap<PathBuf, Vec<(usize, usize)>> = HashMap::new();
+    diff.foreach(
+        &mut |_, _| true,
+        None,
+        Some(&mut |delta, hunk| {
+            if delta.status() == Delta::Deleted {
+                return true;
+            }
+            if let Some(path) = delta.new_file().path() {
+                let start = hunk.new_start().max(1) as usize;
+                let end = start + hunk.new_lines().max(1) as usize - 1;
+                changed_lines
+                    .entry(workdir.join(path))
+                    .or_default()
+                    .push((start, end));
+            }
+            return true;
+        }),
+        None,
+    )?;
+
+    return Ok(changed_lines);
+}
+
+fn open_repository(path: &Path) -> Result<(Repository, PathBuf)> {
+    let repo = Repository::discover(path)
+        .wrap_err_with(|| format!("Couldn't find a git repository at {:?}", path))?;
+    let workdir = repo
+        .workdir()
+        .ok_or_else(|| eyre!("Bare repositories are not supported"))?
+        .canonicalize()?;
+    return Ok((repo, workdir));
+}
+
+// Diff between the merge base of `since` and HEAD, and the working tree.
+fn diff_since<'a>(
+    repo: &'a Repository,
+    since: &str,
+    diff_options: &mut DiffOptions,
+) -> Result<Diff<'a>> {
+    let since_commit = repo
+        .revparse_single(since)
+        .and_then(|object| object.peel_to_commit())
+        .wrap_err_with(|| format!("Couldn't find revision {:?}", since))?;
+    let head_commit = repo.head()?.peel_to_commit()?;
+    let merge_base = repo.merge_base(since_commit.id(), head_commit.id())?;
+    let base_tree = repo.find_commit(merge_base)?.tree()?;
+
+    let diff = repo.diff_tree_to_workdir_with_index(Some(&base_tree), Some(diff_options))?;
+    return Ok(diff);
+}
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use git2::{Repository, Signature};
+    use lantern_git::{get_changed_files, get_changed_lines};
+
+    fn init_repo(name: &str) -> (Repository, PathBuf) {
+        let dir = std::env::temp_dir().join(format!("lantern_git_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let dir = dir.canonicalize().unwrap();
+        let repo = Repository::init(&dir).unwrap();
+        return (repo, dir);
+    }
+
+    fn commit_all(repo: &Repository, message: &str) {
+        let mut index = repo.index().unwrap();
+        index
+            .add_all(["*"].iter(), git2::IndexAddOption::DEFAULT, None)
+            .unwrap();
+        index.update_all(["*"].iter(), None).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = Signature::now("lantern", "lantern@example.com").unwrap();
+        let parents = match repo.head() {
+            Ok(head) => vec![head.peel_to_commit().unwrap()],
+            Err(_) => vec![],
+        };
+        let parents = parents.iter().collect::<Vec<_>>();
+        repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            message,
+            &tree,
+            &parents,
+        )
+        .unwrap();
+    }
+
+    fn write(dir: &Path, name: &str, content: &str) {
+        std::fs::write(dir.join(name), content).unwrap();
+    }
+
+    #[test]
+    fn modified_deleted_and_renamed() {
+        let (repo, dir) = init_repo("changes");
+        write(&dir, "a.ts", "export const A = 1;\n");
+        write(&dir, "b.ts", "export const B = 1;\n");
+        write(
+            &dir,
+            "c.ts",
+            "export const C = 1;\nexport const D = 2;\nexport const E = 3;\n",
+        );
+        commit_all(&repo, "initial");
+        let base = repo
+            .head()
+            .unwrap()
+            .peel_to_commit()
+            .unwrap()
+            .id()
+            .to_string();
+
+        write(&dir, "a.ts", "export const A = 2;\n");
+        std::fs::remove_file(dir.join("b.ts")).unwrap();
+        std::fs::rename(dir.join("c.ts"), dir.join("d.ts")).unwrap();
+        commit_all(&repo, "changes");
+        write(&dir, "e.ts", "export const E = 1;\n");
+
+        let changes = get_changed_files(&dir, &base).unwrap();
+        assert_eq!(changes.modified, vec![dir.join("a.ts"), dir.join("e.ts")]);
+        assert_eq!(changes.deleted, vec![dir.join("b.ts")]);
+        assert_eq!(changes.renamed, vec![(dir.join("c.ts"), dir.join("d.ts"))]);
+        assert_eq!(changes.all().len(), 5);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn unknown_revision() {
+        let (repo, dir) = init_repo("unknown_revision");
+        write(&dir, "a.ts", "export const A = 1;\n");
+        commit_all(&repo, "initial");
+
+        assert!(get_changed_files(&dir, "does-not-exist").is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn changed_lines() {
+        let (repo, dir) = init_repo("changed_lines");
+        write(
+            &dir,
+            "a.ts",
+            "const a = 1;\nconst b = 2;\nconst c = 3;\nconst d = 4;\n",
+        );
+        write(&dir, "b.ts", "export const B = 1;\n");
+        commit_all(&repo, "initial");
+        let base = repo
+            .head()
+            .unwrap()
+            .peel_to_commit()
+            .unwrap()
+            .id()
+            .to_string();
+
+        write(&dir, "a.ts", "const a = 1;\nconst b = 20;\nconst c = 30;\n");
+        write(&dir, "c.ts", "export const C = 1;\nexport const D = 1;\n");
+
+        let changed_lines = get_changed_lines(&dir, &base).unwrap();
+        assert_eq!(changed_lines.len(), 2);
+        assert_eq!(changed_lines[&dir.join("a.ts")], vec![(2, 3)]);
+        assert_eq!(changed_lines[&dir.join("c.ts")], vec![(1, 2)]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
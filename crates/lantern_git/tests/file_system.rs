@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use git2::{Repository, Signature};
+    use lantern_fs::FileSystem;
+    use lantern_git::GitFileSystem;
+
+    fn init_repo(name: &str) -> (Repository, PathBuf) {
+        let dir = std::env::temp_dir().join(format!("lantern_git_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let dir = dir.canonicalize().unwrap();
+        let repo = Repository::init(&dir).unwrap();
+        return (repo, dir);
+    }
+
+    fn commit_all(repo: &Repository, message: &str) {
+        let mut index = repo.index().unwrap();
+        index
+            .add_all(["*"].iter(), git2::IndexAddOption::DEFAULT, None)
+            .unwrap();
+        index.update_all(["*"].iter(), None).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = Signature::now("lantern", "lantern@example.com").unwrap();
+        let parents = match repo.head() {
+            Ok(head) => vec![head.peel_to_commit().unwrap()],
+            Err(_) => vec![],
+        };
+        let parents = parents.iter().collect::<Vec<_>>();
+        repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            message,
+            &tree,
+            &parents,
+        )
+        .unwrap();
+    }
+
+    fn write(dir: &Path, name: &str, content: &str) {
+        std::fs::write(dir.join(name), content).unwrap();
+    }
+
+    #[test]
+    fn reads_committed_files() {
+        let (repo, dir) = init_repo("file_system");
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        write(&dir, "src/a.ts", "export const A = 1;\n");
+        commit_all(&repo, "initial");
+        write(&dir, "src/a.ts", "export const A = 2;\n");
+        write(&dir, "src/b.ts", "export const B = 1;\n");
+
+        let fs = GitFileSystem::open(&dir, "HEAD").unwrap();
+        assert_eq!(
+            fs.read_to_string(&dir.join("src/a.ts")).unwrap(),
+            "export const A = 1;\n"
+        );
+        assert!(fs.read_to_string(&dir.join("src/b.ts")).is_err());
+        assert!(fs.is_file(&dir.join("src/../src/a.ts")));
+        assert!(fs.is_dir(&dir.join("src")));
+        assert_eq!(fs.read_dir(&dir).unwrap(), vec![dir.join("src")]);
+        assert_eq!(
+            fs.read_dir(&dir.join("src")).unwrap(),
+            vec![dir.join("src/a.ts")]
+        );
+    }
+
+    #[test]
+    fn reads_older_revisions() {
+        let (repo, dir) = init_repo("file_system_rev");
+        write(&dir, "a.ts", "export const A = 1;\n");
+        commit_all(&repo, "initial");
+        let initial = repo.head().unwrap().peel_to_commit().unwrap().id();
+        std::fs::remove_file(dir.join("a.ts")).unwrap();
+        write(&dir, "b.ts", "export const B = 1;\n");
+        commit_all(&repo, "rename");
+
+        let fs = GitFileSystem::open(&dir, &initial.to_string()).unwrap();
+        assert!(fs.is_file(&dir.join("a.ts")));
+        assert!(!fs.is_file(&dir.join("b.ts")));
+        assert!(GitFileSystem::open(&dir, "missing").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn follows_symlinked_directories() {
+        let (repo, dir) = init_repo("file_system_symlinks");
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        write(&dir, "src/a.ts", "export const A = 1;\n");
+        std::os::unix::fs::symlink("src", dir.join("lib")).unwrap();
+        std::os::unix::fs::symlink("lib/a.ts", dir.join("alias.ts")).unwrap();
+        commit_all(&repo, "initial");
+
+        let fs = GitFileSystem::open(&dir, "HEAD").unwrap();
+        assert_eq!(
+            fs.read_to_string(&dir.join("lib/a.ts")).unwrap(),
+            "export const A = 1;\n"
+        );
+        assert_eq!(
+            fs.canonicalize(&dir.join("alias.ts")).unwrap(),
+            dir.join("src/a.ts")
+        );
+        assert!(fs.is_dir(&dir.join("lib")));
+        assert!(fs.symlink_metadata(&dir.join("lib")).unwrap().is_symlink);
+        assert!(fs.canonicalize(&dir.join("lib/missing.ts")).is_err());
+    }
+}
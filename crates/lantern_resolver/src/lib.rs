@@ -1,20 +1,30 @@
-use std::path::PathBuf;
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use color_eyre::{eyre::Result, eyre::WrapErr};
-use oxc_resolver::{ResolveOptions, Resolver};
+use oxc_resolver::{FileMetadata, ResolveOptions, ResolverGeneric};
+
+use lantern_fs::{FileSystem, Metadata, OsFileSystem};
 
 #[derive(Debug)]
 pub struct LanternResolver {
-    resolver: Resolver,
+    resolver: ResolverGeneric<ResolverFileSystem>,
 }
 
 impl LanternResolver {
     pub fn new() -> Self {
+        return Self::with_file_system(Arc::new(OsFileSystem));
+    }
+
+    pub fn with_file_system(fs: Arc<dyn FileSystem>) -> Self {
         let mut options = ResolveOptions::default();
         options.extensions = vec![".js".into(), ".json".into(), ".ts".into(), ".tsx".into()];
 
         Self {
-            resolver: Resolver::new(options),
+            resolver: ResolverGeneric::new_with_file_system(ResolverFileSystem(fs), options),
         }
     }
 
@@ -31,3 +41,29 @@ impl LanternResolver {
         }
     }
 }
+
+// Lets oxc_resolver look files up through a lantern file system.
+#[derive(Debug)]
+struct ResolverFileSystem(Arc<dyn FileSystem>);
+
+impl oxc_resolver::FileSystem for ResolverFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        return self.0.read_to_string(path);
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        return self.0.metadata(path).map(to_file_metadata);
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        return self.0.symlink_metadata(path).map(to_file_metadata);
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        return self.0.canonicalize(path);
+    }
+}
+
+fn to_file_metadata(metadata: Metadata) -> FileMetadata {
+    return FileMetadata::new(metadata.is_file, metadata.is_dir, metadata.is_symlink);
+}
@@ -1,25 +1,27 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, sync::Arc};
 
 mod module;
 pub mod symbol;
 pub mod symbols_map;
 
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{Report, Result, WrapErr};
 
 use oxc_allocator::Allocator;
 use oxc_ast::{
     ast::{
         BindingPatternKind, Declaration, ExportDefaultDeclarationKind, ImportDeclarationSpecifier,
+        StringLiteral,
     },
     Visit,
 };
+use oxc_span::Span;
 
+use lantern_fs::{FileSystem, OsFileSystem};
 use lantern_parse_ts::parse_ts;
-use lantern_resolver::LanternResolver;
 
-use module::LNModule;
+pub use module::LNModule;
 use symbol::{LNFileReference, LNSymbol, LNSymbolData};
-use symbols_map::LNSymbolsMap;
+use symbols_map::{LNSymbolsMap, LNUnresolvedImport};
 
 pub struct LNVisitor<'a> {
     module_id: usize,
@@ -27,13 +29,23 @@ pub struct LNVisitor<'a> {
     symbols_map: &'a mut LNSymbolsMap,
 }
 
-pub fn build_symbols_map(entry_points: &Vec<PathBuf>) -> Result<LNSymbolsMap> {
-    let resolver = LanternResolver::new();
-    let mut ln_symbols_map = LNSymbolsMap::new(resolver);
+pub fn build_symbols_map(entry_points: &[PathBuf]) -> Result<LNSymbolsMap> {
+    return build_symbols_map_with_fs(entry_points, Arc::new(OsFileSystem));
+}
+
+/// Same as `build_symbols_map`, but sources are read from `fs` instead of the disk, e.g.
+/// unsaved editor buffers or in-memory fixtures.
+pub fn build_symbols_map_with_fs(
+    entry_points: &[PathBuf],
+    fs: Arc<dyn FileSystem>,
+) -> Result<LNSymbolsMap> {
+    let mut ln_symbols_map = LNSymbolsMap::new(fs.clone());
     let allocator = Allocator::default();
 
     for entry_point in entry_points {
-        let path = entry_point.canonicalize()?;
+        let path = fs
+            .canonicalize(entry_point)
+            .wrap_err_with(|| format!("Couldn't find entry point {:?}", entry_point))?;
         ln_symbols_map.add_module(LNModule {
             file_path: path,
             symbols: vec![],
@@ -49,12 +61,18 @@ pub fn build_symbols_map(entry_points: &Vec<PathBuf>) -> Result<LNSymbolsMap> {
         } else {
             break;
         };
-        let source = std::fs::read_to_string(&module.file_path).unwrap();
+        let source = fs
+            .read_to_string(&module.file_path)
+            .wrap_err_with(|| format!("Couldn't read {:?}", module.file_path))?;
         let path = module.file_path.clone();
-        let program = parse_ts(&allocator, &source, &path)?;
-        let parent = module.file_path.parent().unwrap().to_path_buf();
-        let mut visitor = LNVisitor::new(id, parent, &mut ln_symbols_map);
-        visitor.visit_program(&program);
+        {
+            let program = parse_ts(&allocator, &source, &path)?;
+            let parent = module.file_path.parent().unwrap().to_path_buf();
+            let mut visitor = LNVisitor::new(id, parent, &mut ln_symbols_map);
+            visitor.visit_program(&program);
+        }
+        // Keep the source around for annotations and size reports.
+        ln_symbols_map.set_module_source(id, source);
         id += 1;
     }
 
@@ -69,6 +87,25 @@ impl<'a> LNVisitor<'a> {
             symbols_map: ts_s,
         };
     }
+
+    // Unresolved sources go to stderr so they don't end up in machine readable output.
+    fn add_unresolved_import(&mut self, source: &StringLiteral, error: Report) {
+        eprintln!("{}", error);
+        self.symbols_map.add_unresolved_import(LNUnresolvedImport {
+            module_id: self.module_id,
+            source: source.value.to_string(),
+            span: source.span,
+            // The context names the absolute directory, the cause is enough to explain it.
+            error: error.root_cause().to_string(),
+        });
+    }
+
+    // Attributes every symbol added since `first_symbol_id` to the statement declaring it.
+    fn set_declaration_span(&mut self, first_symbol_id: usize, span: Span) {
+        for symbol_id in first_symbol_id..self.symbols_map.symbols.len() {
+            self.symbols_map.set_declaration_span(symbol_id, span);
+        }
+    }
 }
 
 impl<'a> Visit<'a> for LNVisitor<'a> {
@@ -78,12 +115,13 @@ impl<'a> Visit<'a> for LNVisitor<'a> {
             .symbols_map
             .resolve(&self.parent_path, decl.source.value.to_string());
 
-        if maybe_path.is_err() {
-            println!("{}", maybe_path.err().unwrap());
-            return;
-        }
-
-        let path = maybe_path.unwrap();
+        let path = match maybe_path {
+            Ok(path) => path,
+            Err(err) => {
+                self.add_unresolved_import(&decl.source, err);
+                return;
+            }
+        };
 
         let module_id = self.symbols_map.add_module(LNModule {
             file_path: path,
@@ -118,6 +156,8 @@ impl<'a> Visit<'a> for LNVisitor<'a> {
         &mut self,
         decl: &oxc_ast::ast::ExportDefaultDeclaration<'a>,
     ) {
+        let first_symbol_id = self.symbols_map.symbols.len();
+
         match &decl.declaration {
             ExportDefaultDeclarationKind::ArrayExpression(_)
             | ExportDefaultDeclarationKind::ArrowFunctionExpression(_)
@@ -289,6 +329,8 @@ impl<'a> Visit<'a> for LNVisitor<'a> {
                 );
             }
         }
+
+        self.set_declaration_span(first_symbol_id, decl.span);
     }
 
     // export function a() {}
@@ -304,6 +346,9 @@ impl<'a> Visit<'a> for LNVisitor<'a> {
     // export { a as a2, b as b2 } from "./exports_decl";
     // export { c };
     fn visit_export_named_declaration(&mut self, decl: &oxc_ast::ast::ExportNamedDeclaration<'a>) {
+        let first_symbol_id = self.symbols_map.symbols.len();
+        let declaration_span = decl.span;
+
         if let Some(decl) = &decl.declaration {
             match decl {
                 Declaration::VariableDeclaration(decl) => {
@@ -386,18 +431,21 @@ impl<'a> Visit<'a> for LNVisitor<'a> {
                 }
                 _ => {}
             }
+
+            self.set_declaration_span(first_symbol_id, declaration_span);
         } else {
             let src = if let Some(src) = &decl.source {
                 let maybe_path = self
                     .symbols_map
                     .resolve(&self.parent_path, src.value.to_string());
 
-                if maybe_path.is_err() {
-                    println!("{}", maybe_path.err().unwrap());
-                    return;
-                }
-
-                let path = maybe_path.unwrap();
+                let path = match maybe_path {
+                    Ok(path) => path,
+                    Err(err) => {
+                        self.add_unresolved_import(src, err);
+                        return;
+                    }
+                };
 
                 let module_id = self.symbols_map.add_module(LNModule {
                     file_path: path,
@@ -438,12 +486,13 @@ impl<'a> Visit<'a> for LNVisitor<'a> {
             .symbols_map
             .resolve(&self.parent_path, import_decl.source.value.to_string());
 
-        if maybe_path.is_err() {
-            println!("{}", maybe_path.err().unwrap());
-            return;
-        }
-
-        let path = maybe_path.unwrap();
+        let path = match maybe_path {
+            Ok(path) => path,
+            Err(err) => {
+                self.add_unresolved_import(&import_decl.source, err);
+                return;
+            }
+        };
 
         let module_id = self.symbols_map.add_module(LNModule {
             file_path: path,
@@ -52,6 +52,54 @@ impl LNSymbol {
             LNSymbolData::ImportNamed(name, _, _, _, _) => Some(name),
         }
     }
+
+    /// Name other modules import this export by, "default" for default exports.
+    pub fn get_export_name(&self) -> Option<&str> {
+        match &self.symbol {
+            LNSymbolData::ExportClassDecl(name, _)
+            | LNSymbolData::ExportDecl(name, _)
+            | LNSymbolData::ExportEnumDecl(name, _)
+            | LNSymbolData::ExportFnDecl(name, _)
+            | LNSymbolData::ExportInterfaceDecl(name, _)
+            | LNSymbolData::ExportTypeAliasDecl(name, _)
+            | LNSymbolData::ExportNamed(_, name, _, _) => Some(name),
+            LNSymbolData::ExportDefaultExpr(_)
+            | LNSymbolData::ExportDefaultClassDecl(_, _)
+            | LNSymbolData::ExportDefaultFnDecl(_, _)
+            | LNSymbolData::ExportDefaultInterfaceDecl(_, _)
+            | LNSymbolData::ExportDefaultIdentifier(_, _)
+            | LNSymbolData::ExportDefaultCallExpression(_, _)
+            | LNSymbolData::ExportDefaultConditionalExpression(_, _, _) => Some("default"),
+            LNSymbolData::ExportAll(_)
+            | LNSymbolData::ImportDefault(_, _, _, _)
+            | LNSymbolData::ImportStar(_, _, _, _)
+            | LNSymbolData::ImportNamed(_, _, _, _, _) => None,
+        }
+    }
+
+    /// Type imports and exports of declarations that only exist as types.
+    pub fn is_type_only(&self) -> bool {
+        return match &self.symbol {
+            LNSymbolData::ImportDefault(_, _, _, type_only)
+            | LNSymbolData::ImportStar(_, _, _, type_only)
+            | LNSymbolData::ImportNamed(_, _, _, _, type_only) => *type_only,
+            LNSymbolData::ExportInterfaceDecl(_, _)
+            | LNSymbolData::ExportTypeAliasDecl(_, _)
+            | LNSymbolData::ExportDefaultInterfaceDecl(_, _) => true,
+            _ => false,
+        };
+    }
+
+    pub fn get_file_reference(&self) -> Option<&LNFileReference> {
+        match &self.symbol {
+            LNSymbolData::ExportAll(file_ref) => Some(file_ref),
+            LNSymbolData::ExportNamed(_, _, _, file_ref) => file_ref.as_ref(),
+            LNSymbolData::ImportDefault(_, _, file_ref, _) => Some(file_ref),
+            LNSymbolData::ImportStar(_, _, file_ref, _) => Some(file_ref),
+            LNSymbolData::ImportNamed(_, _, _, file_ref, _) => Some(file_ref),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
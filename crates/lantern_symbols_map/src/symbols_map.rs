@@ -1,30 +1,65 @@
-use std::{collections::HashMap, path::PathBuf};
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use color_eyre::eyre::Result;
 
 use oxc_span::Span;
 
+use lantern_fs::FileSystem;
 use lantern_resolver::LanternResolver;
 
-use crate::{symbol::LNSymbol, LNModule};
+use crate::{
+    symbol::{LNSymbol, LNSymbolData},
+    LNModule,
+};
+
+/// Import or re-export whose source couldn't be resolved to a file.
+#[derive(Debug, Clone)]
+pub struct LNUnresolvedImport {
+    pub module_id: usize,
+    pub source: String,
+    /// Span of the source string literal.
+    pub span: Span,
+    /// Why the resolver failed, without the importing directory.
+    pub error: String,
+}
+
+impl LNUnresolvedImport {
+    /// `error` with paths under `root` made relative, so output doesn't depend on where
+    /// the project is checked out.
+    pub fn get_relative_error(&self, root: &Path) -> String {
+        let prefix = format!("{}{}", root.display(), std::path::MAIN_SEPARATOR);
+        return self.error.replace(&prefix, "");
+    }
+}
 
 #[derive(Debug)]
 pub struct LNSymbolsMap {
     pub modules: Vec<LNModule>,
     pub symbols: Vec<LNSymbol>,
+    pub unresolved_imports: Vec<LNUnresolvedImport>,
     path_to_module_id: HashMap<String, usize>,
     sources: HashMap<usize, String>,
+    declaration_spans: HashMap<usize, Span>,
     resolver: LanternResolver,
+    fs: Arc<dyn FileSystem>,
 }
 
 impl LNSymbolsMap {
-    pub fn new(resolver: LanternResolver) -> Self {
+    /// Modules are read and resolved through `fs`.
+    pub fn new(fs: Arc<dyn FileSystem>) -> Self {
         Self {
             modules: Vec::new(),
             symbols: Vec::new(),
+            unresolved_imports: Vec::new(),
             path_to_module_id: HashMap::new(),
             sources: HashMap::new(),
-            resolver,
+            declaration_spans: HashMap::new(),
+            resolver: LanternResolver::with_file_system(fs.clone()),
+            fs,
         }
     }
 
@@ -57,16 +92,32 @@ impl LNSymbolsMap {
         return self.path_to_module_id.get(path).copied();
     }
 
+    pub fn get_file_system(&self) -> &Arc<dyn FileSystem> {
+        return &self.fs;
+    }
+
     pub fn get_module_source(&mut self, module_id: usize) -> &str {
         if self.sources.contains_key(&module_id) {
             &self.sources[&module_id]
         } else {
-            let source = std::fs::read_to_string(&self.modules[module_id].file_path).unwrap();
+            let source = self
+                .fs
+                .read_to_string(&self.modules[module_id].file_path)
+                .unwrap();
             self.sources.insert(module_id, source.clone());
             &self.sources[&module_id]
         }
     }
 
+    pub fn set_module_source(&mut self, module_id: usize, source: String) {
+        self.sources.insert(module_id, source);
+    }
+
+    /// Size of the module source in bytes.
+    pub fn get_module_size(&mut self, module_id: usize) -> usize {
+        return self.get_module_source(module_id).len();
+    }
+
     pub fn read_span_from_module(&mut self, module_id: usize, span: &Span) -> &str {
         let source = self.get_module_source(module_id);
         return source[span.start as usize..span.end as usize].as_ref();
@@ -79,6 +130,55 @@ impl LNSymbolsMap {
         return id;
     }
 
+    /// Records the span of the whole statement an export symbol was declared in.
+    pub fn set_declaration_span(&mut self, symbol_id: usize, span: Span) {
+        self.declaration_spans.insert(symbol_id, span);
+    }
+
+    /// Span of the statement a symbol was declared in, falls back to the symbol's own
+    /// span for symbols without a recorded declaration.
+    pub fn get_declaration_span(&self, symbol_id: usize) -> &Span {
+        return self
+            .declaration_spans
+            .get(&symbol_id)
+            .unwrap_or_else(|| self.symbols[symbol_id].get_span());
+    }
+
+    /// Names a module exports, including names re-exported with `export *`.
+    pub fn get_export_names(&self, module_id: usize) -> Vec<String> {
+        fn collect(
+            ln_map: &LNSymbolsMap,
+            module_id: usize,
+            visited: &mut HashSet<usize>,
+            names: &mut Vec<String>,
+        ) {
+            if !visited.insert(module_id) {
+                return;
+            }
+            for symbol_id in &ln_map.modules[module_id].symbols {
+                let symbol = &ln_map.symbols[*symbol_id];
+                if let LNSymbolData::ExportAll(file_ref) = &symbol.symbol {
+                    let mut re_exported = Vec::new();
+                    collect(ln_map, file_ref.module_id, visited, &mut re_exported);
+                    // export * doesn't re-export the default export.
+                    names.extend(re_exported.into_iter().filter(|name| name != "default"));
+                } else if let Some(name) = symbol.get_export_name() {
+                    names.push(name.to_owned());
+                }
+            }
+        }
+
+        let mut names = Vec::new();
+        collect(self, module_id, &mut HashSet::new(), &mut names);
+        names.sort();
+        names.dedup();
+        return names;
+    }
+
+    pub fn add_unresolved_import(&mut self, unresolved_import: LNUnresolvedImport) {
+        self.unresolved_imports.push(unresolved_import);
+    }
+
     pub fn resolve(&self, parent_path: &PathBuf, path: String) -> Result<PathBuf> {
         return self.resolver.resolve(parent_path, &path);
     }
@@ -95,4 +195,21 @@ impl LNSymbolsMap {
         let source = source[0..(span.start + 1) as usize].to_string();
         return source.lines().count();
     }
+
+    /// 1-based line and column of a byte offset in the module source, columns count characters.
+    pub fn get_position_from_offset(&mut self, module_id: usize, offset: u32) -> (usize, usize) {
+        let source = self.get_module_source(module_id);
+        let before = &source[0..offset as usize];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+        return (line, before[line_start..].chars().count() + 1);
+    }
+
+    /// First and last line covered by a span, both 1-based and inclusive.
+    pub fn get_line_range_from_span(&mut self, module_id: usize, span: &Span) -> (usize, usize) {
+        let start = self.get_line_number_from_span(module_id, span);
+        let source = self.get_module_source(module_id);
+        let end = source[0..span.end as usize].lines().count();
+        return (start, end.max(start));
+    }
 }
@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+    use std::{path::Path, sync::Arc};
+
+    use lantern_fs::{MemoryFileSystem, OverlayFileSystem};
+    use lantern_symbols_map::build_symbols_map_with_fs;
+    use lantern_testing::fixture_project;
+
+    #[test]
+    fn builds_from_memory() {
+        let project = fixture_project! {
+            "index.ts" => r#"import { a } from "./a"; import { b } from "./b"; import "./missing";"#,
+            "a.ts" => "export const a = 1;",
+            "b/index.ts" => r#"export * from "../a"; export const b = 2;"#,
+        };
+        let ep = vec![project.path("index.ts")];
+        let mut sm = build_symbols_map_with_fs(&ep, project.file_system()).unwrap();
+
+        let paths = sm
+            .modules
+            .iter()
+            .map(|module| module.file_path.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                project.path("index.ts"),
+                project.path("a.ts"),
+                project.path("b/index.ts")
+            ]
+        );
+        assert_eq!(sm.get_module_source(1), "export const a = 1;");
+        assert_eq!(sm.unresolved_imports.len(), 1);
+        assert_eq!(sm.unresolved_imports[0].source, "./missing");
+    }
+
+    #[test]
+    fn overlay_replaces_files() {
+        let project = fixture_project! {
+            "index.ts" => r#"import { a } from "./a";"#,
+            "a.ts" => "export const a = 1;",
+        };
+        let mut upper = MemoryFileSystem::new();
+        upper.add_file(
+            &project.path("index.ts"),
+            r#"import { b } from "./b";"#.to_string(),
+        );
+        upper.add_file(&project.path("b.ts"), "export const b = 1;".to_string());
+        let fs = OverlayFileSystem::new(Arc::new(upper), project.file_system());
+
+        let ep = vec![project.path("index.ts")];
+        let sm = build_symbols_map_with_fs(&ep, Arc::new(fs)).unwrap();
+        assert_eq!(sm.modules.len(), 2);
+        assert_eq!(sm.modules[1].file_path, Path::new("/fixture/b.ts"));
+    }
+}
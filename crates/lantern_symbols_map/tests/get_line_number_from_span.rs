@@ -24,4 +24,16 @@ mod tests {
         let line = sm.get_line_number_from_span(symbol.module_id, span);
         assert_eq!(line, 8);
     }
+
+    #[test]
+    fn position_from_offset() {
+        let path_buf = load_fixture!("exports_decl.ts");
+        let ep = vec![path_buf];
+        let mut sm = build_symbols_map(&ep).unwrap();
+        let symbol = sm.symbols[5].clone();
+        let span = symbol.get_span();
+        let position = sm.get_position_from_offset(symbol.module_id, span.start);
+        assert_eq!(position, (8, 3));
+        assert_eq!(sm.get_position_from_offset(symbol.module_id, 0), (1, 1));
+    }
 }
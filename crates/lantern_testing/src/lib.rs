@@ -1,3 +1,10 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use lantern_fs::{FileSystem, MemoryFileSystem};
+
 #[macro_export]
 macro_rules! load_fixture {
     ($fname:expr) => {
@@ -6,3 +13,41 @@ macro_rules! load_fixture {
             .join($fname);
     };
 }
+
+/// Declares a project inline in a test, e.g.
+/// `fixture_project! { "index.ts" => "import { a } from './a';", "a.ts" => "export const a = 1;" }`.
+/// Files live in memory under `FIXTURE_PROJECT_ROOT`.
+#[macro_export]
+macro_rules! fixture_project {
+    ($($path:expr => $source:expr),* $(,)?) => {
+        $crate::FixtureProject::new(&[$(($path, $source)),*])
+    };
+}
+
+pub const FIXTURE_PROJECT_ROOT: &str = "/fixture";
+
+pub struct FixtureProject {
+    fs: Arc<MemoryFileSystem>,
+}
+
+impl FixtureProject {
+    pub fn new(files: &[(&str, &str)]) -> Self {
+        let mut fs = MemoryFileSystem::new();
+        for (path, source) in files {
+            fs.add_file(
+                &Path::new(FIXTURE_PROJECT_ROOT).join(path),
+                source.to_string(),
+            );
+        }
+        return Self { fs: Arc::new(fs) };
+    }
+
+    /// Absolute path of a file relative to the project root.
+    pub fn path(&self, path: &str) -> PathBuf {
+        return Path::new(FIXTURE_PROJECT_ROOT).join(path);
+    }
+
+    pub fn file_system(&self) -> Arc<dyn FileSystem> {
+        return self.fs.clone();
+    }
+}